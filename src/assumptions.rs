@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+
+use traits::*;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// A set of assumptions about the values of the variables, such as bounds and divisibility,
+/// which allow functions like `max_with` and `floor_with` to simplify further.
+pub struct Assumptions<I, C>
+    where I: Id,
+          C: Coefficient {
+    /// The inclusive lower and upper bounds of the variables.
+    bounds: HashMap<I, (Option<C>, Option<C>)>,
+    /// The known divisors of the variables.
+    divisors: HashMap<I, C>,
+}
+
+impl<I, C> Assumptions<I, C>
+    where I: Id,
+          C: Coefficient {
+    /// Creates an empty set of assumptions.
+    pub fn new() -> Self {
+        Assumptions {
+            bounds: HashMap::new(),
+            divisors: HashMap::new(),
+        }
+    }
+
+    /// Checks whether there are no assumptions about any variable.
+    pub fn is_empty(&self) -> bool {
+        self.bounds.is_empty() && self.divisors.is_empty()
+    }
+
+    /// Assumes that the variable `id` is at least `value`.
+    pub fn assume_at_least(&mut self, id: I, value: C) {
+        let bounds = self.bounds.entry(id).or_insert((None, None));
+        bounds.0 = match bounds.0.take() {
+            Some(ref lower) if *lower > value => Some(lower.clone()),
+            _ => Some(value),
+        };
+    }
+
+    /// Assumes that the variable `id` is at most `value`.
+    pub fn assume_at_most(&mut self, id: I, value: C) {
+        let bounds = self.bounds.entry(id).or_insert((None, None));
+        bounds.1 = match bounds.1.take() {
+            Some(ref upper) if *upper < value => Some(upper.clone()),
+            _ => Some(value),
+        };
+    }
+
+    /// Assumes that the variable `id` is strictly positive.
+    pub fn assume_positive(&mut self, id: I) {
+        self.assume_at_least(id, C::one());
+    }
+
+    /// Assumes that the variable `id` is a multiple of `divisor`. Only the magnitude of the
+    /// divisor matters, while being a multiple of zero means that the variable is zero.
+    pub fn assume_multiple_of(&mut self, id: I, divisor: C) {
+        if divisor == C::zero() {
+            self.assume_at_least(id.clone(), C::zero());
+            self.assume_at_most(id, C::zero());
+            return;
+        }
+        let known = self.divisor(&id);
+        self.divisors.insert(id, known.least_common_multiple(&divisor));
+    }
+
+    /// Returns the assumed lower bound of the variable `id`, if any.
+    pub fn lower_bound(&self, id: &I) -> Option<&C> {
+        self.bounds.get(id).and_then(|bounds| bounds.0.as_ref())
+    }
+
+    /// Returns the assumed upper bound of the variable `id`, if any.
+    pub fn upper_bound(&self, id: &I) -> Option<&C> {
+        self.bounds.get(id).and_then(|bounds| bounds.1.as_ref())
+    }
+
+    /// Returns the greatest known divisor of the variable `id`, which is one if nothing is known.
+    pub fn divisor(&self, id: &I) -> C {
+        self.divisors.get(id).cloned().unwrap_or_else(C::one)
+    }
+
+    /// Returns all of the variables which have an assumed lower or upper bound.
+    pub fn bounded(&self) -> Vec<&I> {
+        self.bounds.keys().collect()
+    }
+}
+
+impl<I, C> Default for Assumptions<I, C>
+    where I: Id,
+          C: Coefficient {
+    fn default() -> Self {
+        Assumptions::new()
+    }
+}
@@ -1,15 +1,21 @@
-use std::cmp::{Ord, Ordering};
+use std::cmp::{Ord, Ordering, min, max};
 use std::rc::Rc;
 use std::collections::{HashMap, HashSet};
 
 use traits::*;
 use polynomial::Polynomial;
+use condition::{Condition, Relation};
+use registry::{FnId, call_function};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "repr_c", repr(C))]
 /// A composite expression (tagged union) of a variable or an irreducible function
-/// (floor, ceil, max, min).
+/// (floor, ceil, max, min, mod, truncated and rounded division, abs, sign, gcd, lcm,
+/// powers of two, integer logarithms, piecewise selection and user-defined functions).
+///
+/// The commutative functions (max, min, gcd, lcm) take any number of arguments,
+/// which are kept sorted in descending order.
 pub enum Composite<I, C, P>
     where I: Id,
           C: Coefficient,
@@ -17,8 +23,20 @@ pub enum Composite<I, C, P>
     Variable(I),
     Floor(Rc<Polynomial<I, C, P>>, Rc<Polynomial<I, C, P>>),
     Ceil(Rc<Polynomial<I, C, P>>, Rc<Polynomial<I, C, P>>),
-    Min(Rc<Polynomial<I, C, P>>, Rc<Polynomial<I, C, P>>),
-    Max(Rc<Polynomial<I, C, P>>, Rc<Polynomial<I, C, P>>),
+    Min(Vec<Rc<Polynomial<I, C, P>>>),
+    Max(Vec<Rc<Polynomial<I, C, P>>>),
+    Mod(Rc<Polynomial<I, C, P>>, Rc<Polynomial<I, C, P>>),
+    TruncDiv(Rc<Polynomial<I, C, P>>, Rc<Polynomial<I, C, P>>),
+    RoundDiv(Rc<Polynomial<I, C, P>>, Rc<Polynomial<I, C, P>>),
+    Abs(Rc<Polynomial<I, C, P>>),
+    Sign(Rc<Polynomial<I, C, P>>),
+    Gcd(Vec<Rc<Polynomial<I, C, P>>>),
+    Lcm(Vec<Rc<Polynomial<I, C, P>>>),
+    Exp2(Rc<Polynomial<I, C, P>>),
+    Log2Floor(Rc<Polynomial<I, C, P>>),
+    Log2Ceil(Rc<Polynomial<I, C, P>>),
+    Select(Rc<Condition<I, C, P>>, Rc<Polynomial<I, C, P>>, Rc<Polynomial<I, C, P>>),
+    Apply(FnId, Vec<Rc<Polynomial<I, C, P>>>),
 }
 
 impl<I, C, P> Composite<I, C, P>
@@ -34,39 +52,335 @@ impl<I, C, P> Composite<I, C, P>
                     .cloned()
                     .ok_or((x.clone(), format!("Value not provided for {}.", x)))
             }
-            Composite::Floor(ref x, ref y) => {
-                let v_x = x.eval(values)?;
-                let v_y = y.eval(values)?;
-                if v_y == C::zero() {
-                    Err((I::default(), "Attempting division by zero.".to_string()))
-                } else {
-                    Ok(C::div_floor(&v_x, &v_y))
+            Composite::Floor(ref x, ref y) |
+            Composite::Ceil(ref x, ref y) |
+            Composite::Mod(ref x, ref y) |
+            Composite::TruncDiv(ref x, ref y) |
+            Composite::RoundDiv(ref x, ref y) => self.divide(x.eval(values)?, y.eval(values)?),
+            Composite::Min(ref args) => {
+                let mut result = args[0].eval(values)?;
+                for arg in &args[1..] {
+                    let v_arg = arg.eval(values)?;
+                    if v_arg < result {
+                        result = v_arg;
+                    }
                 }
+                Ok(result)
             }
-            Composite::Ceil(ref x, ref y) => {
-                let v_x = x.eval(values)?;
-                let v_y = y.eval(values)?;
-                if v_y == C::zero() {
-                    Err((I::default(), "Attempting division by zero.".to_string()))
-                } else {
-                    let (d, rem) = v_x.div_rem(&v_y);
-                    if rem == C::zero() {
-                        Ok(d)
+            Composite::Max(ref args) => {
+                let mut result = args[0].eval(values)?;
+                for arg in &args[1..] {
+                    let v_arg = arg.eval(values)?;
+                    if v_arg > result {
+                        result = v_arg;
+                    }
+                }
+                Ok(result)
+            }
+            Composite::Abs(ref x) |
+            Composite::Sign(ref x) |
+            Composite::Exp2(ref x) |
+            Composite::Log2Floor(ref x) |
+            Composite::Log2Ceil(ref x) => self.apply(x.eval(values)?),
+            Composite::Gcd(ref args) => {
+                let mut result = C::zero();
+                for arg in args {
+                    result = result.greatest_common_divisor(&arg.eval(values)?);
+                }
+                Ok(result)
+            }
+            Composite::Lcm(ref args) => {
+                let mut result = C::one();
+                for arg in args {
+                    let v_arg = arg.eval(values)?;
+                    if v_arg == C::zero() {
+                        result = C::zero();
+                    } else if result != C::zero() {
+                        result = result.least_common_multiple(&v_arg);
+                    }
+                }
+                Ok(result)
+            }
+            Composite::Select(ref cond, ref x, ref y) => {
+                if cond.eval(values)? { x.eval(values) } else { y.eval(values) }
+            }
+            Composite::Apply(ref id, ref args) => {
+                let mut v_args = Vec::with_capacity(args.len());
+                for arg in args {
+                    v_args.push(arg.eval(values)?);
+                }
+                call_function(id, &v_args).map_err(|e| (I::default(), e))
+            }
+        }
+    }
+
+    /// Evaluates the `Composite` in floating point, as in `Polynomial::eval_f64`.
+    pub fn eval_f64(&self, values: &HashMap<I, f64>) -> f64 {
+        match *self {
+            Composite::Variable(ref x) => values.get(x).cloned().unwrap_or(f64::NAN),
+            Composite::Floor(ref x, ref y) => (x.eval_f64(values) / y.eval_f64(values)).floor(),
+            Composite::Ceil(ref x, ref y) => (x.eval_f64(values) / y.eval_f64(values)).ceil(),
+            Composite::Mod(ref x, ref y) => {
+                let (v_x, v_y) = (x.eval_f64(values), y.eval_f64(values));
+                v_x - v_y * (v_x / v_y).floor()
+            }
+            Composite::TruncDiv(ref x, ref y) => (x.eval_f64(values) / y.eval_f64(values)).trunc(),
+            Composite::RoundDiv(ref x, ref y) => (x.eval_f64(values) / y.eval_f64(values)).round(),
+            Composite::Min(ref args) => {
+                args.iter().map(|arg| arg.eval_f64(values)).fold(f64::INFINITY, min_f64)
+            }
+            Composite::Max(ref args) => {
+                args.iter().map(|arg| arg.eval_f64(values)).fold(f64::NEG_INFINITY, max_f64)
+            }
+            Composite::Abs(ref x) => x.eval_f64(values).abs(),
+            Composite::Sign(ref x) => {
+                let v_x = x.eval_f64(values);
+                // Unlike `signum` the sign of zero is zero
+                if v_x == 0.0 { 0.0 } else { v_x.signum() }
+            }
+            Composite::Gcd(ref args) => {
+                args.iter().map(|arg| arg.eval_f64(values)).fold(0.0, gcd_f64)
+            }
+            Composite::Lcm(ref args) => {
+                args.iter().map(|arg| arg.eval_f64(values)).fold(1.0, |acc, v| {
+                    if acc == 0.0 || v == 0.0 { 0.0 } else { (acc * v / gcd_f64(acc, v)).abs() }
+                })
+            }
+            Composite::Exp2(ref x) => x.eval_f64(values).exp2(),
+            Composite::Log2Floor(ref x) => x.eval_f64(values).log2().floor(),
+            Composite::Log2Ceil(ref x) => x.eval_f64(values).log2().ceil(),
+            Composite::Select(ref cond, ref x, ref y) => {
+                if cond.eval_f64(values) { x.eval_f64(values) } else { y.eval_f64(values) }
+            }
+            Composite::Apply(ref id, ref args) => {
+                let v_args: Vec<f64> = args.iter().map(|arg| arg.eval_f64(values)).collect();
+                call_function(id, &v_args).unwrap_or(f64::NAN)
+            }
+        }
+    }
+
+    /// Computes sound lower and upper bounds of the `Composite`, given the provided mapping
+    /// of identifiers to the inclusive intervals of their values.
+    pub fn eval_interval(&self, values: &HashMap<I, (C, C)>) -> Result<(C, C), (I, String)> {
+        match *self {
+            Composite::Variable(ref x) => {
+                values
+                    .get(x)
+                    .cloned()
+                    .ok_or((x.clone(), format!("Value not provided for {}.", x)))
+            }
+            Composite::Floor(ref x, ref y) |
+            Composite::Ceil(ref x, ref y) |
+            Composite::TruncDiv(ref x, ref y) |
+            Composite::RoundDiv(ref x, ref y) => {
+                let (x_low, x_high) = x.eval_interval(values)?;
+                let (y_low, y_high) = y.eval_interval(values)?;
+                // For a divisor of a fixed sign the division is monotone in both arguments,
+                // hence the bounds are attained at the corners
+                let mut bounds = None;
+                for (low, high) in divisor_ranges(y_low, y_high) {
+                    for v_x in &[&x_low, &x_high] {
+                        for v_y in &[&low, &high] {
+                            let value = self.divide((*v_x).clone(), (*v_y).clone())?;
+                            bounds = Some(hull(bounds, (value.clone(), value)));
+                        }
+                    }
+                }
+                bounds.ok_or((I::default(), "Attempting division by zero.".to_string()))
+            }
+            Composite::Mod(ref x, ref y) => {
+                let (x_low, x_high) = x.eval_interval(values)?;
+                let (y_low, y_high) = y.eval_interval(values)?;
+                let mut bounds = None;
+                for (low, high) in divisor_ranges(y_low, y_high) {
+                    let range = if low > C::zero() {
+                        if x_low >= C::zero() && x_high < low {
+                            (x_low.clone(), x_high.clone())
+                        } else {
+                            (C::zero(), high - C::one())
+                        }
+                    } else if x_high <= C::zero() && x_low > high {
+                        (x_low.clone(), x_high.clone())
                     } else {
+                        (low + C::one(), C::zero())
+                    };
+                    bounds = Some(hull(bounds, range));
+                }
+                bounds.ok_or((I::default(), "Attempting division by zero.".to_string()))
+            }
+            Composite::Min(ref args) |
+            Composite::Max(ref args) => {
+                let mut result = args[0].eval_interval(values)?;
+                for arg in &args[1..] {
+                    let (low, high) = arg.eval_interval(values)?;
+                    result = match *self {
+                        Composite::Min(_) => (min(result.0, low), min(result.1, high)),
+                        _ => (max(result.0, low), max(result.1, high)),
+                    };
+                }
+                Ok(result)
+            }
+            Composite::Abs(ref x) |
+            Composite::Sign(ref x) |
+            Composite::Exp2(ref x) |
+            Composite::Log2Floor(ref x) |
+            Composite::Log2Ceil(ref x) => {
+                let (low, high) = x.eval_interval(values)?;
+                match *self {
+                    Composite::Abs(_) if high <= C::zero() => Ok((-high, -low)),
+                    Composite::Abs(_) if low < C::zero() => Ok((C::zero(), max(-low, high))),
+                    _ => {
+                        // The rest are non-decreasing, thus only their domain needs care
+                        let low = match *self {
+                            Composite::Exp2(_) if high >= C::zero() => max(low, C::zero()),
+                            Composite::Log2Floor(_) |
+                            Composite::Log2Ceil(_) if high > C::zero() => max(low, C::one()),
+                            _ => low,
+                        };
+                        Ok((self.apply(low)?, self.apply(high)?))
+                    }
+                }
+            }
+            Composite::Gcd(ref args) => {
+                // The gcd is at most the magnitude of any non-zero argument
+                let mut low = C::zero();
+                let mut high = None;
+                let mut magnitude = C::zero();
+                for arg in args {
+                    let (arg_low, arg_high) = arg.eval_interval(values)?;
+                    let arg_magnitude = max(-arg_low.clone(), arg_high.clone());
+                    if arg_low > C::zero() || arg_high < C::zero() {
+                        low = C::one();
+                        high = Some(match high {
+                            Some(high) => min(high, arg_magnitude.clone()),
+                            None => arg_magnitude.clone(),
+                        });
+                    }
+                    magnitude = max(magnitude, arg_magnitude);
+                }
+                Ok((low, high.unwrap_or(magnitude)))
+            }
+            Composite::Lcm(ref args) => {
+                // The lcm of non-zero arguments is at least the magnitude of each of them
+                // and at most the product of their magnitudes
+                let mut low = C::zero();
+                let mut high = C::one();
+                let mut non_zero = true;
+                for arg in args {
+                    let (arg_low, arg_high) = arg.eval_interval(values)?;
+                    if arg_low > C::zero() {
+                        low = max(low, arg_low.clone());
+                    } else if arg_high < C::zero() {
+                        low = max(low, -arg_high.clone());
+                    } else {
+                        non_zero = false;
+                    }
+                    high *= max(-arg_low, arg_high);
+                }
+                Ok((if non_zero { low } else { C::zero() }, high))
+            }
+            Composite::Select(ref cond, ref x, ref y) => {
+                let difference = &cond.left - &cond.right;
+                let (low, high) = difference.eval_interval(values)?;
+                if holds_everywhere(cond.relation, &low, &high) {
+                    x.eval_interval(values)
+                } else if holds_everywhere(cond.relation.negate(), &low, &high) {
+                    y.eval_interval(values)
+                } else {
+                    Ok(hull(Some(x.eval_interval(values)?), y.eval_interval(values)?))
+                }
+            }
+            Composite::Apply(ref id, ref args) => {
+                let mut v_args = Vec::with_capacity(args.len());
+                for arg in args {
+                    match arg.eval_interval(values)? {
+                        (low, high) if low == high => v_args.push(low),
+                        _ => {
+                            return Err((I::default(), format!("Cannot bound the function {}.", id)))
+                        }
+                    }
+                }
+                let value = call_function(id, &v_args).map_err(|e| (I::default(), e))?;
+                Ok((value.clone(), value))
+            }
+        }
+    }
+
+    /// Evaluates the division-like `Composite` for the given values of its arguments.
+    fn divide(&self, v_x: C, v_y: C) -> Result<C, (I, String)> {
+        if v_y == C::zero() {
+            return Err((I::default(), "Attempting division by zero.".to_string()));
+        }
+        match *self {
+            Composite::Floor(_, _) => Ok(v_x.floor_div(&v_y)),
+            Composite::Ceil(_, _) => Ok(-(-v_x).floor_div(&v_y)),
+            Composite::Mod(_, _) => Ok(v_x.floor_mod(&v_y)),
+            Composite::TruncDiv(_, _) => Ok(trunc_div(&v_x, &v_y)),
+            Composite::RoundDiv(_, _) => {
+                // Rounds half away from zero, e.g. |rem| >= |v_y| - |rem|
+                let d = trunc_div(&v_x, &v_y);
+                let rem = v_x.clone() - d.clone() * v_y.clone();
+                let abs_rem = if rem < C::zero() { -rem } else { rem };
+                let abs_y = if v_y < C::zero() { -v_y.clone() } else { v_y.clone() };
+                if abs_rem.clone() >= abs_y - abs_rem {
+                    if (v_x < C::zero()) == (v_y < C::zero()) {
                         Ok(d + C::one())
+                    } else {
+                        Ok(d - C::one())
                     }
+                } else {
+                    Ok(d)
                 }
             }
-            Composite::Min(ref x, ref y) => {
-                let v_x = x.eval(values)?;
-                let v_y = y.eval(values)?;
-                Ok(if v_x < v_y { v_x } else { v_y })
+            _ => unreachable!(),
+        }
+    }
+
+    /// Evaluates the single argument `Composite` for the given value of its argument.
+    fn apply(&self, v_x: C) -> Result<C, (I, String)> {
+        match *self {
+            Composite::Abs(_) => Ok(if v_x < C::zero() { -v_x } else { v_x }),
+            Composite::Sign(_) => {
+                if v_x > C::zero() {
+                    Ok(C::one())
+                } else if v_x < C::zero() {
+                    Ok(-C::one())
+                } else {
+                    Ok(C::zero())
+                }
             }
-            Composite::Max(ref x, ref y) => {
-                let v_x = x.eval(values)?;
-                let v_y = y.eval(values)?;
-                Ok(if v_x > v_y { v_x } else { v_y })
+            Composite::Exp2(_) => {
+                if v_x < C::zero() {
+                    Err((I::default(), "Attempting negative power of two.".to_string()))
+                } else {
+                    match v_x.as_usize() {
+                        Some(power) => Ok(::num::pow(C::one() + C::one(), power)),
+                        None => Err((I::default(), "Attempting non-integer power of two.".into())),
+                    }
+                }
             }
+            Composite::Log2Floor(_) |
+            Composite::Log2Ceil(_) => {
+                if v_x <= C::zero() {
+                    Err((
+                        I::default(),
+                        "Attempting logarithm of a non-positive number.".to_string(),
+                    ))
+                } else {
+                    let two = C::one() + C::one();
+                    let mut result = C::zero();
+                    let mut power = C::one();
+                    while power.clone() * two.clone() <= v_x {
+                        power *= two.clone();
+                        result += C::one();
+                    }
+                    match *self {
+                        Composite::Log2Ceil(_) if power < v_x => Ok(result + C::one()),
+                        _ => Ok(result),
+                    }
+                }
+            }
+            _ => unreachable!(),
         }
     }
 
@@ -79,19 +393,60 @@ impl<I, C, P> Composite<I, C, P>
             Composite::Variable(ref id) => str = format(id.clone()),
             Composite::Floor(_, _) => str.push_str("floor("),
             Composite::Ceil(_, _) => str.push_str("ceil("),
-            Composite::Max(_, _) => str.push_str("max("),
-            Composite::Min(_, _) => str.push_str("min("),
+            Composite::Max(_) => str.push_str("max("),
+            Composite::Min(_) => str.push_str("min("),
+            Composite::Mod(_, _) => str.push_str("mod("),
+            Composite::TruncDiv(_, _) => str.push_str("trunc_div("),
+            Composite::RoundDiv(_, _) => str.push_str("round_div("),
+            Composite::Abs(_) => str.push_str("abs("),
+            Composite::Sign(_) => str.push_str("sign("),
+            Composite::Gcd(_) => str.push_str("gcd("),
+            Composite::Lcm(_) => str.push_str("lcm("),
+            Composite::Exp2(_) => str.push_str("exp2("),
+            Composite::Log2Floor(_) => str.push_str("log2_floor("),
+            Composite::Log2Ceil(_) => str.push_str("log2_ceil("),
+            Composite::Select(_, _, _) => str.push_str("select("),
+            Composite::Apply(ref id, _) => {
+                str.push_str(&id.0);
+                str.push('(');
+            }
         }
         match *self {
             Composite::Variable(_) => {}
             Composite::Floor(ref x, ref y) |
             Composite::Ceil(ref x, ref y) |
-            Composite::Max(ref x, ref y) |
-            Composite::Min(ref x, ref y) => {
+            Composite::Mod(ref x, ref y) |
+            Composite::TruncDiv(ref x, ref y) |
+            Composite::RoundDiv(ref x, ref y) => {
                 str.push_str(&x.to_code(format));
                 str.push_str(", ");
                 str.push_str(&y.to_code(format));
-                str.push_str(")");
+                str.push(')');
+            }
+            Composite::Abs(ref x) |
+            Composite::Sign(ref x) |
+            Composite::Exp2(ref x) |
+            Composite::Log2Floor(ref x) |
+            Composite::Log2Ceil(ref x) => {
+                str.push_str(&x.to_code(format));
+                str.push(')');
+            }
+            Composite::Select(ref cond, ref x, ref y) => {
+                str.push_str(&cond.to_code(format));
+                str.push_str(", ");
+                str.push_str(&x.to_code(format));
+                str.push_str(", ");
+                str.push_str(&y.to_code(format));
+                str.push(')');
+            }
+            Composite::Max(ref args) |
+            Composite::Min(ref args) |
+            Composite::Gcd(ref args) |
+            Composite::Lcm(ref args) |
+            Composite::Apply(_, ref args) => {
+                let args: Vec<String> = args.iter().map(|arg| arg.to_code(format)).collect();
+                str.push_str(&args.join(", "));
+                str.push(')');
             }
         }
         str
@@ -105,11 +460,122 @@ impl<I, C, P> Composite<I, C, P>
             }
             Composite::Floor(ref x, ref y) |
             Composite::Ceil(ref x, ref y) |
-            Composite::Max(ref x, ref y) |
-            Composite::Min(ref x, ref y) => {
+            Composite::Mod(ref x, ref y) |
+            Composite::TruncDiv(ref x, ref y) |
+            Composite::RoundDiv(ref x, ref y) => {
+                x.unique_identifiers(unique);
+                y.unique_identifiers(unique);
+            }
+            Composite::Abs(ref x) |
+            Composite::Sign(ref x) |
+            Composite::Exp2(ref x) |
+            Composite::Log2Floor(ref x) |
+            Composite::Log2Ceil(ref x) => {
+                x.unique_identifiers(unique);
+            }
+            Composite::Select(ref cond, ref x, ref y) => {
+                cond.unique_identifiers(unique);
                 x.unique_identifiers(unique);
                 y.unique_identifiers(unique);
             }
+            Composite::Max(ref args) |
+            Composite::Min(ref args) |
+            Composite::Gcd(ref args) |
+            Composite::Lcm(ref args) |
+            Composite::Apply(_, ref args) => {
+                for arg in args {
+                    arg.unique_identifiers(unique);
+                }
+            }
+        }
+    }
+
+    /// Converts the coefficient type of a variable, failing for any other composite, as `f` does
+    /// not commute with it (see `Polynomial::map_coefficients`).
+    pub fn map_coefficients<C2, F>(&self, _f: &F) -> Result<Composite<I, C2, P>, (I, String)>
+        where C2: Coefficient,
+              F: Fn(&C) -> C2 {
+        match *self {
+            Composite::Variable(ref x) => Ok(Composite::Variable(x.clone())),
+            _ => {
+                Err((
+                    I::default(),
+                    format!("Can not map the coefficients of the composite {}.", self),
+                ))
+            }
+        }
+    }
+
+    /// Converts the `Composite` into one with different identifier, coefficient and power types
+    /// by converting every polynomial inside of it, keeping the arguments of commutative
+    /// functions sorted. Fails if a coefficient or a power can not be converted.
+    pub fn try_map<I2, C2, P2, FI, FC, FP>(&self,
+                                           id: &FI,
+                                           coefficient: &FC,
+                                           power: &FP)
+                                           -> Result<Composite<I2, C2, P2>, (I, String)>
+        where I2: Id,
+              C2: Coefficient,
+              P2: Power,
+              FI: Fn(&I) -> I2,
+              FC: Fn(&C) -> Option<C2>,
+              FP: Fn(&P) -> Option<P2> {
+        let map = |x: &Rc<Polynomial<I, C, P>>| -> Result<_, (I, String)> {
+            Ok(Rc::new(x.try_map(id, coefficient, power)?))
+        };
+        let map_all = |args: &[Rc<Polynomial<I, C, P>>]| -> Result<Vec<_>, (I, String)> {
+            args.iter().map(&map).collect()
+        };
+        let map_commutative = |args: &[Rc<Polynomial<I, C, P>>]| -> Result<Vec<_>, (I, String)> {
+            let mut args = map_all(args)?;
+            args.sort_by(|a, b| Ord::cmp(b, a));
+            Ok(args)
+        };
+        Ok(match *self {
+            Composite::Variable(ref x) => Composite::Variable(id(x)),
+            Composite::Floor(ref x, ref y) => Composite::Floor(map(x)?, map(y)?),
+            Composite::Ceil(ref x, ref y) => Composite::Ceil(map(x)?, map(y)?),
+            Composite::Min(ref args) => Composite::Min(map_commutative(args)?),
+            Composite::Max(ref args) => Composite::Max(map_commutative(args)?),
+            Composite::Mod(ref x, ref y) => Composite::Mod(map(x)?, map(y)?),
+            Composite::TruncDiv(ref x, ref y) => Composite::TruncDiv(map(x)?, map(y)?),
+            Composite::RoundDiv(ref x, ref y) => Composite::RoundDiv(map(x)?, map(y)?),
+            Composite::Abs(ref x) => Composite::Abs(map(x)?),
+            Composite::Sign(ref x) => Composite::Sign(map(x)?),
+            Composite::Gcd(ref args) => Composite::Gcd(map_commutative(args)?),
+            Composite::Lcm(ref args) => Composite::Lcm(map_commutative(args)?),
+            Composite::Exp2(ref x) => Composite::Exp2(map(x)?),
+            Composite::Log2Floor(ref x) => Composite::Log2Floor(map(x)?),
+            Composite::Log2Ceil(ref x) => Composite::Log2Ceil(map(x)?),
+            Composite::Select(ref cond, ref x, ref y) => {
+                let cond = cond.try_map(id, coefficient, power)?;
+                Composite::Select(Rc::new(cond), map(x)?, map(y)?)
+            }
+            Composite::Apply(ref name, ref args) => Composite::Apply(name.clone(), map_all(args)?),
+        })
+    }
+
+    /// The rank of each kind of `Composite`, used for ordering composites of different kinds,
+    /// where a lower rank means a greater composite.
+    fn rank(&self) -> u8 {
+        match *self {
+            Composite::Variable(_) => 0,
+            Composite::Max(_) => 1,
+            Composite::Min(_) => 2,
+            Composite::Ceil(_, _) => 3,
+            Composite::Floor(_, _) => 4,
+            Composite::Mod(_, _) => 5,
+            Composite::TruncDiv(_, _) => 6,
+            Composite::RoundDiv(_, _) => 7,
+            Composite::Abs(_) => 8,
+            Composite::Sign(_) => 9,
+            Composite::Gcd(_) => 10,
+            Composite::Lcm(_) => 11,
+            Composite::Exp2(_) => 12,
+            Composite::Log2Floor(_) => 13,
+            Composite::Log2Ceil(_) => 14,
+            Composite::Select(_, _, _) => 15,
+            Composite::Apply(_, _) => 16,
         }
     }
 }
@@ -124,12 +590,37 @@ impl<I, C, P> ::std::fmt::Display for Composite<I, C, P>
             Composite::Variable(ref id) => write!(f, "{}", id),
             Composite::Floor(ref x, ref y) => write!(f, "floor({}, {})", x, y),
             Composite::Ceil(ref x, ref y) => write!(f, "ceil({}, {})", x, y),
-            Composite::Min(ref x, ref y) => write!(f, "min({}, {})", x, y),
-            Composite::Max(ref x, ref y) => write!(f, "max({}, {})", x, y),
+            Composite::Min(ref args) => write_function(f, "min", args),
+            Composite::Max(ref args) => write_function(f, "max", args),
+            Composite::Mod(ref x, ref y) => write!(f, "mod({}, {})", x, y),
+            Composite::TruncDiv(ref x, ref y) => write!(f, "trunc_div({}, {})", x, y),
+            Composite::RoundDiv(ref x, ref y) => write!(f, "round_div({}, {})", x, y),
+            Composite::Abs(ref x) => write!(f, "abs({})", x),
+            Composite::Sign(ref x) => write!(f, "sign({})", x),
+            Composite::Gcd(ref args) => write_function(f, "gcd", args),
+            Composite::Lcm(ref args) => write_function(f, "lcm", args),
+            Composite::Exp2(ref x) => write!(f, "exp2({})", x),
+            Composite::Log2Floor(ref x) => write!(f, "log2_floor({})", x),
+            Composite::Log2Ceil(ref x) => write!(f, "log2_ceil({})", x),
+            Composite::Select(ref cond, ref x, ref y) => {
+                write!(f, "select({}, {}, {})", cond, x, y)
+            }
+            Composite::Apply(ref id, ref args) => write_function(f, &id.0, args),
         }
     }
 }
 
+fn write_function<I, C, P>(f: &mut ::std::fmt::Formatter,
+                           name: &str,
+                           args: &[Rc<Polynomial<I, C, P>>])
+                           -> ::std::fmt::Result
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    let args: Vec<String> = args.iter().map(|arg| format!("{}", arg)).collect();
+    write!(f, "{}({})", name, args.join(", "))
+}
+
 
 impl<I, C, P> PartialOrd for Composite<I, C, P>
     where I: Id,
@@ -145,61 +636,109 @@ impl<I, C, P> Ord for Composite<I, C, P>
           C: Coefficient,
           P: Power {
     fn cmp(&self, other: &Composite<I, C, P>) -> Ordering {
-        match *self {
-            Composite::Variable(ref id) => {
-                match *other {
-                    Composite::Variable(ref o_id) => Ord::cmp(o_id, id),
-                    _ => Ordering::Greater,
+        match (self, other) {
+            (Composite::Variable(id), Composite::Variable(o_id)) => Ord::cmp(o_id, id),
+            (Composite::Floor(x, y), Composite::Floor(o_x, o_y)) |
+            (Composite::Ceil(x, y), Composite::Ceil(o_x, o_y)) |
+            (Composite::Mod(x, y), Composite::Mod(o_x, o_y)) |
+            (Composite::TruncDiv(x, y), Composite::TruncDiv(o_x, o_y)) |
+            (Composite::RoundDiv(x, y), Composite::RoundDiv(o_x, o_y)) => {
+                match Ord::cmp(x, o_x) {
+                    Ordering::Equal => Ord::cmp(y, o_y),
+                    v => v,
                 }
             }
-            Composite::Max(ref x, ref y) => {
-                match *other {
-                    Composite::Variable(_) => Ordering::Less,
-                    Composite::Max(ref o_x, ref o_y) => {
+            (Composite::Abs(x), Composite::Abs(o_x)) |
+            (Composite::Sign(x), Composite::Sign(o_x)) |
+            (Composite::Exp2(x), Composite::Exp2(o_x)) |
+            (Composite::Log2Floor(x), Composite::Log2Floor(o_x)) |
+            (Composite::Log2Ceil(x), Composite::Log2Ceil(o_x)) => Ord::cmp(x, o_x),
+            (Composite::Min(args), Composite::Min(o_args)) |
+            (Composite::Max(args), Composite::Max(o_args)) |
+            (Composite::Gcd(args), Composite::Gcd(o_args)) |
+            (Composite::Lcm(args), Composite::Lcm(o_args)) => Ord::cmp(args, o_args),
+            (Composite::Select(cond, x, y), Composite::Select(o_cond, o_x, o_y)) => {
+                match Ord::cmp(cond, o_cond) {
+                    Ordering::Equal => {
                         match Ord::cmp(x, o_x) {
                             Ordering::Equal => Ord::cmp(y, o_y),
                             v => v,
                         }
                     }
-                    _ => Ordering::Greater,
+                    v => v,
                 }
             }
-            Composite::Min(ref x, ref y) => {
-                match *other {
-                    Composite::Variable(_) |
-                    Composite::Max(_, _) => Ordering::Less,
-                    Composite::Min(ref o_x, ref o_y) => {
-                        match Ord::cmp(x, o_x) {
-                            Ordering::Equal => Ord::cmp(y, o_y),
-                            v => v,
-                        }
-                    }
-                    _ => Ordering::Greater,
-                }
-            }
-            Composite::Ceil(ref x, ref y) => {
-                match *other {
-                    Composite::Ceil(ref o_x, ref o_y) => {
-                        match Ord::cmp(x, o_x) {
-                            Ordering::Equal => Ord::cmp(y, o_y),
-                            v => v,
-                        }
-                    }
-                    Composite::Floor(_, _) => Ordering::Greater,
-                    _ => Ordering::Less,
-                }
-            }
-            Composite::Floor(ref x, ref y) => {
-                match *other {
-                    Composite::Floor(ref o_x, ref o_y) => {
-                        match Ord::cmp(x, o_x) {
-                            Ordering::Equal => Ord::cmp(y, o_y),
-                            v => v,
-                        }
-                    }
-                    _ => Ordering::Greater,
+            (Composite::Apply(id, args), Composite::Apply(o_id, o_args)) => {
+                match Ord::cmp(id, o_id) {
+                    Ordering::Equal => Ord::cmp(args, o_args),
+                    v => v,
                 }
             }
+            _ => Ord::cmp(&other.rank(), &self.rank()),
         }
     }
 }
+
+/// The minimum of two floats, which unlike `f64::min` is NaN if either of them is.
+fn min_f64(x: f64, y: f64) -> f64 {
+    if x.is_nan() || y.is_nan() { f64::NAN } else { x.min(y) }
+}
+
+/// The maximum of two floats, which unlike `f64::max` is NaN if either of them is.
+fn max_f64(x: f64, y: f64) -> f64 {
+    if x.is_nan() || y.is_nan() { f64::NAN } else { x.max(y) }
+}
+
+/// The non-negative greatest common divisor of two integral floats, by Euclid's algorithm.
+fn gcd_f64(x: f64, y: f64) -> f64 {
+    if x.is_nan() || y.is_nan() {
+        return f64::NAN;
+    }
+    let (mut x, mut y) = (x.abs(), y.abs());
+    while y != 0.0 {
+        let r = x % y;
+        x = y;
+        y = r;
+    }
+    x
+}
+
+/// Computes the quotient `x / y` rounded towards zero.
+fn trunc_div<C: Coefficient>(x: &C, y: &C) -> C {
+    if (*x < C::zero()) == (*y < C::zero()) {
+        x.floor_div(y)
+    } else {
+        -(-x.clone()).floor_div(y)
+    }
+}
+
+/// Splits the interval of a divisor into its negative and positive parts, excluding zero.
+fn divisor_ranges<C: Coefficient>(low: C, high: C) -> Vec<(C, C)> {
+    let mut ranges = Vec::new();
+    if low < C::zero() {
+        ranges.push((low.clone(), min(high.clone(), -C::one())));
+    }
+    if high > C::zero() {
+        ranges.push((max(low, C::one()), high));
+    }
+    ranges
+}
+
+/// Returns the smallest interval containing both of the intervals.
+fn hull<C: Coefficient>(bounds: Option<(C, C)>, other: (C, C)) -> (C, C) {
+    match bounds {
+        Some((low, high)) => (min(low, other.0), max(high, other.1)),
+        None => other,
+    }
+}
+
+/// Checks whether the relation `d <relation> 0` holds for every `d` in the interval.
+fn holds_everywhere<C: Coefficient>(relation: Relation, low: &C, high: &C) -> bool {
+    let zero = C::zero();
+    match relation {
+        Relation::Equal => *low == zero && *high == zero,
+        Relation::NotEqual => *low > zero || *high < zero,
+        Relation::Less | Relation::LessOrEqual => relation.holds(high, &zero),
+        Relation::Greater | Relation::GreaterOrEqual => relation.holds(low, &zero),
+    }
+}
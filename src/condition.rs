@@ -0,0 +1,168 @@
+use std::collections::{HashMap, HashSet};
+
+use traits::*;
+use polynomial::Polynomial;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "repr_c", repr(C))]
+/// A relation used for comparing two polynomials.
+pub enum Relation {
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+impl Relation {
+    /// Returns the relation which holds when the two sides are swapped
+    /// (e.g. `a < b` is the same as `b > a`).
+    pub fn flip(&self) -> Relation {
+        match *self {
+            Relation::Equal => Relation::Equal,
+            Relation::NotEqual => Relation::NotEqual,
+            Relation::Less => Relation::Greater,
+            Relation::LessOrEqual => Relation::GreaterOrEqual,
+            Relation::Greater => Relation::Less,
+            Relation::GreaterOrEqual => Relation::LessOrEqual,
+        }
+    }
+
+    /// Returns the relation which holds exactly when this one does not
+    /// (e.g. `a < b` is the opposite of `a >= b`).
+    pub fn negate(&self) -> Relation {
+        match *self {
+            Relation::Equal => Relation::NotEqual,
+            Relation::NotEqual => Relation::Equal,
+            Relation::Less => Relation::GreaterOrEqual,
+            Relation::LessOrEqual => Relation::Greater,
+            Relation::Greater => Relation::LessOrEqual,
+            Relation::GreaterOrEqual => Relation::Less,
+        }
+    }
+
+    /// Checks whether the relation holds between the two values.
+    pub fn holds<C: PartialOrd>(&self, left: &C, right: &C) -> bool {
+        match *self {
+            Relation::Equal => left == right,
+            Relation::NotEqual => left != right,
+            Relation::Less => left < right,
+            Relation::LessOrEqual => left <= right,
+            Relation::Greater => left > right,
+            Relation::GreaterOrEqual => left >= right,
+        }
+    }
+}
+
+impl ::std::fmt::Display for Relation {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            Relation::Equal => write!(f, "=="),
+            Relation::NotEqual => write!(f, "!="),
+            Relation::Less => write!(f, "<"),
+            Relation::LessOrEqual => write!(f, "<="),
+            Relation::Greater => write!(f, ">"),
+            Relation::GreaterOrEqual => write!(f, ">="),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "repr_c", repr(C))]
+/// A condition comparing two polynomials, represented as `left <relation> right`.
+pub struct Condition<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    /// The left hand side of the comparison.
+    pub left: Polynomial<I, C, P>,
+    /// The relation between the two sides.
+    pub relation: Relation,
+    /// The right hand side of the comparison.
+    pub right: Polynomial<I, C, P>,
+}
+
+impl<I, C, P> Condition<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    /// Creates the condition `left <relation> right`.
+    pub fn new<T1, T2>(left: T1, relation: Relation, right: T2) -> Self
+        where T1: AsRef<Polynomial<I, C, P>>,
+              T2: AsRef<Polynomial<I, C, P>> {
+        Condition {
+            left: left.as_ref().clone(),
+            relation,
+            right: right.as_ref().clone(),
+        }
+    }
+
+    /// Evaluates the `Condition` given the provided mapping of identifiers to value assignments.
+    pub fn eval(&self, values: &HashMap<I, C>) -> Result<bool, (I, String)> {
+        let v_left = self.left.eval(values)?;
+        let v_right = self.right.eval(values)?;
+        Ok(self.relation.holds(&v_left, &v_right))
+    }
+
+    /// Evaluates the `Condition` in floating point, as in `Polynomial::eval_f64`.
+    pub fn eval_f64(&self, values: &HashMap<I, f64>) -> bool {
+        self.relation.holds(&self.left.eval_f64(values), &self.right.eval_f64(values))
+    }
+
+    /// Returns a code equivalent string representation of the `Condition`.
+    /// The `format` specifies a function how to render the identifiers.
+    pub fn to_code<F>(&self, format: &F) -> String
+        where F: ::std::ops::Fn(I) -> String {
+        format!("{} {} {}", self.left.to_code(format), self.relation, self.right.to_code(format))
+    }
+
+    /// Fills into the `HashSet` all of the identifiers used in this `Condition`.
+    pub fn unique_identifiers(&self, unique: &mut HashSet<I>) {
+        self.left.unique_identifiers(unique);
+        self.right.unique_identifiers(unique);
+    }
+
+    /// Converts the coefficients of both sides of the condition with `f`, as in
+    /// `Polynomial::map_coefficients`.
+    pub fn map_coefficients<C2, F>(&self, f: &F) -> Result<Condition<I, C2, P>, (I, String)>
+        where C2: Coefficient,
+              F: Fn(&C) -> C2 {
+        Ok(Condition::new(
+            self.left.map_coefficients(f)?,
+            self.relation,
+            self.right.map_coefficients(f)?,
+        ))
+    }
+
+    /// Converts both sides of the condition into different identifier, coefficient and power
+    /// types, as in `Polynomial::try_map`.
+    pub fn try_map<I2, C2, P2, FI, FC, FP>(&self,
+                                           id: &FI,
+                                           coefficient: &FC,
+                                           power: &FP)
+                                           -> Result<Condition<I2, C2, P2>, (I, String)>
+        where I2: Id,
+              C2: Coefficient,
+              P2: Power,
+              FI: Fn(&I) -> I2,
+              FC: Fn(&C) -> Option<C2>,
+              FP: Fn(&P) -> Option<P2> {
+        Ok(Condition::new(
+            self.left.try_map(id, coefficient, power)?,
+            self.relation,
+            self.right.try_map(id, coefficient, power)?,
+        ))
+    }
+}
+
+impl<I, C, P> ::std::fmt::Display for Condition<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "{} {} {}", self.left, self.relation, self.right)
+    }
+}
@@ -0,0 +1,258 @@
+use std::cmp::Ordering;
+use std::convert::AsRef;
+use num::rational::Ratio;
+use num::Zero;
+
+use traits::*;
+use monomial::Monomial;
+use polynomial::Polynomial;
+use composite::Composite;
+
+/// A polynomial over the rationals, where each term is represented by the vector of exponents
+/// of every atom and its coefficient. The terms are sorted in descending lexicographic order.
+type Sparse<C> = Vec<(Vec<usize>, Ratio<C>)>;
+
+/// Eliminates the variables `vars` from the system of equations `p_1 = 0, ..., p_n = 0`,
+/// given by the `polynomials`.
+///
+/// The result is a set of generators of the elimination ideal, e.g. polynomial relations
+/// which hold between the remaining variables whenever the original equations hold.
+/// These are obtained from a reduced Gröbner basis of the equations under a lexicographic
+/// ordering, where the eliminated variables are ordered first.
+/// Any composite expression (e.g. `floor`) is treated as an independent variable,
+/// which is eliminated as well if it depends on any of the `vars`.
+/// Any negative powers are cleared by multiplying each equation with its common denominator,
+/// which is assumed to be non-zero.
+pub fn eliminate<I, C, P, T>(polynomials: &[T], vars: &[I]) -> Vec<Polynomial<I, C, P>>
+    where I: Id,
+          C: IntegerCoefficient,
+          P: Power,
+          T: AsRef<Polynomial<I, C, P>> {
+    let polynomials: Vec<Polynomial<I, C, P>> = polynomials.iter()
+        .map(|p| p.as_ref() * &p.as_ref().common_denominator())
+        .collect();
+    // Collect all of the atoms, with the eliminated ones first
+    let mut eliminated: Vec<Composite<I, C, P>> =
+        vars.iter().map(|id| Composite::Variable(id.clone())).collect();
+    let mut remaining: Vec<Composite<I, C, P>> = Vec::new();
+    for p in &polynomials {
+        for m in &p.monomials {
+            for (c, _) in &m.powers {
+                if eliminated.contains(c) || remaining.contains(c) {
+                    continue;
+                }
+                let mut unique = ::std::collections::HashSet::new();
+                c.unique_identifiers(&mut unique);
+                if vars.iter().any(|id| unique.contains(id)) {
+                    eliminated.push(c.clone());
+                } else {
+                    remaining.push(c.clone());
+                }
+            }
+        }
+    }
+    let n_eliminated = eliminated.len();
+    let atoms: Vec<Composite<I, C, P>> = eliminated.into_iter().chain(remaining).collect();
+
+    let basis = groebner_basis(
+        polynomials
+            .iter()
+            .map(|p| to_sparse(p, &atoms))
+            .filter(|p| !p.is_empty())
+            .collect(),
+    );
+    basis.iter()
+        .filter(|g| g.iter().all(|(exps, _)| exps[..n_eliminated].iter().all(|&e| e == 0)))
+        .map(|g| from_sparse(g, &atoms))
+        .collect()
+}
+
+fn to_sparse<I, C, P>(polynomial: &Polynomial<I, C, P>, atoms: &[Composite<I, C, P>]) -> Sparse<C>
+    where I: Id,
+          C: IntegerCoefficient,
+          P: Power {
+    let mut result: Sparse<C> = Vec::new();
+    for m in &polynomial.monomials {
+        let mut exps = vec![0; atoms.len()];
+        for (c, pow) in &m.powers {
+            let index = atoms.iter().position(|atom| atom == c).unwrap();
+            exps[index] += pow.to_usize().unwrap();
+        }
+        result = add(&result, &vec![(exps, Ratio::from_integer(m.coefficient.clone()))]);
+    }
+    result
+}
+
+fn from_sparse<I, C, P>(polynomial: &[(Vec<usize>, Ratio<C>)],
+                        atoms: &[Composite<I, C, P>])
+                        -> Polynomial<I, C, P>
+    where I: Id,
+          C: IntegerCoefficient,
+          P: Power {
+    // Clear the denominators and make the polynomial primitive with a positive leading term
+    let denominator = polynomial.iter().fold(C::one(), |acc, t| acc.lcm(t.1.denom()));
+    let numerators: Vec<C> = polynomial.iter()
+        .map(|t| t.1.numer().clone() * (denominator.clone() / t.1.denom().clone()))
+        .collect();
+    let mut content = numerators.iter().fold(C::zero(), |acc, c| acc.gcd(c));
+    if numerators[0] < C::zero() {
+        content = -content;
+    }
+    let mut result = Polynomial { monomials: Vec::new() };
+    for ((exps, _), numerator) in polynomial.iter().zip(numerators) {
+        let mut term = Polynomial::from(numerator / content.clone());
+        for (atom, &e) in atoms.iter().zip(exps.iter()) {
+            if e > 0 {
+                term *= &Monomial {
+                    coefficient: C::one(),
+                    powers: vec![(atom.clone(), P::from_usize(e).unwrap())],
+                };
+            }
+        }
+        result += &term;
+    }
+    result
+}
+
+/// Computes the reduced Gröbner basis using Buchberger's algorithm.
+fn groebner_basis<C>(polynomials: Vec<Sparse<C>>) -> Vec<Sparse<C>>
+    where C: IntegerCoefficient {
+    let mut basis: Vec<Sparse<C>> = polynomials.into_iter().map(|p| monic(&p)).collect();
+    let mut pairs: Vec<(usize, usize)> = Vec::new();
+    for j in 0..basis.len() {
+        for i in 0..j {
+            pairs.push((i, j));
+        }
+    }
+    while let Some((i, j)) = pairs.pop() {
+        let (ref lm_i, _) = basis[i][0];
+        let (ref lm_j, _) = basis[j][0];
+        // Buchberger's first criterion - coprime leading monomials reduce to zero
+        if lm_i.iter().zip(lm_j.iter()).all(|(&a, &b)| a == 0 || b == 0) {
+            continue;
+        }
+        let lcm: Vec<usize> = lm_i.iter().zip(lm_j.iter()).map(|(&a, &b)| a.max(b)).collect();
+        let s = sub(
+            &shift(&basis[i], &quotient(&lcm, lm_i), &Ratio::from_integer(C::one())),
+            &shift(&basis[j], &quotient(&lcm, lm_j), &Ratio::from_integer(C::one())),
+        );
+        let r = normal_form(&s, &basis);
+        if !r.is_empty() {
+            basis.push(monic(&r));
+            let n = basis.len() - 1;
+            for k in 0..n {
+                pairs.push((k, n));
+            }
+        }
+    }
+    // Keep only the elements with leading monomials minimal with respect to divisibility
+    let mut minimal: Vec<Sparse<C>> = Vec::new();
+    for (i, g) in basis.iter().enumerate() {
+        let redundant = basis.iter().enumerate().any(|(j, h)| {
+            j != i && divides(&h[0].0, &g[0].0) && (h[0].0 != g[0].0 || j < i)
+        });
+        if !redundant {
+            minimal.push(g.clone());
+        }
+    }
+    // Interreduce the basis
+    for i in 0..minimal.len() {
+        let others: Vec<Sparse<C>> = minimal.iter()
+            .enumerate()
+            .filter(|&(j, _)| j != i)
+            .map(|(_, g)| g.clone())
+            .collect();
+        let head = vec![minimal[i][0].clone()];
+        let tail = normal_form(&minimal[i][1..].to_vec(), &others);
+        minimal[i] = add(&head, &tail);
+    }
+    minimal.sort_by(|a, b| compare(b, a));
+    minimal
+}
+
+/// Fully reduces `polynomial` with respect to the `basis`.
+fn normal_form<C>(polynomial: &Sparse<C>, basis: &[Sparse<C>]) -> Sparse<C>
+    where C: IntegerCoefficient {
+    let mut remainder: Sparse<C> = Vec::new();
+    let mut p = polynomial.clone();
+    while !p.is_empty() {
+        match basis.iter().find(|g| divides(&g[0].0, &p[0].0)) {
+            Some(g) => {
+                let factor = p[0].1.clone() / g[0].1.clone();
+                p = sub(&p, &shift(g, &quotient(&p[0].0, &g[0].0), &factor));
+            }
+            None => remainder.push(p.remove(0)),
+        }
+    }
+    remainder
+}
+
+fn monic<C>(polynomial: &Sparse<C>) -> Sparse<C>
+    where C: IntegerCoefficient {
+    let leading = polynomial[0].1.clone();
+    polynomial.iter().map(|(exps, c)| (exps.clone(), c.clone() / leading.clone())).collect()
+}
+
+fn divides(a: &[usize], b: &[usize]) -> bool {
+    a.iter().zip(b.iter()).all(|(x, y)| x <= y)
+}
+
+fn quotient(a: &[usize], b: &[usize]) -> Vec<usize> {
+    a.iter().zip(b.iter()).map(|(x, y)| x - y).collect()
+}
+
+/// Multiplies the polynomial by the term `factor * x^exps`.
+fn shift<C>(polynomial: &Sparse<C>, exps: &[usize], factor: &Ratio<C>) -> Sparse<C>
+    where C: IntegerCoefficient {
+    polynomial.iter()
+        .map(|(e, c)| {
+            (e.iter().zip(exps.iter()).map(|(x, y)| x + y).collect(), c.clone() * factor.clone())
+        })
+        .collect()
+}
+
+fn add<C>(p: &Sparse<C>, q: &Sparse<C>) -> Sparse<C>
+    where C: IntegerCoefficient {
+    let mut result: Sparse<C> = Vec::with_capacity(p.len() + q.len());
+    let mut i = 0;
+    let mut j = 0;
+    while i < p.len() && j < q.len() {
+        match Ord::cmp(&p[i].0, &q[j].0) {
+            Ordering::Greater => {
+                result.push(p[i].clone());
+                i += 1;
+            }
+            Ordering::Less => {
+                result.push(q[j].clone());
+                j += 1;
+            }
+            Ordering::Equal => {
+                let c = p[i].1.clone() + q[j].1.clone();
+                if !c.is_zero() {
+                    result.push((p[i].0.clone(), c));
+                }
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    result.extend(p[i..].iter().cloned());
+    result.extend(q[j..].iter().cloned());
+    result
+}
+
+fn sub<C>(p: &Sparse<C>, q: &Sparse<C>) -> Sparse<C>
+    where C: IntegerCoefficient {
+    add(p, &q.iter().map(|(e, c)| (e.clone(), -c.clone())).collect())
+}
+
+fn compare<C>(p: &Sparse<C>, q: &Sparse<C>) -> Ordering
+    where C: IntegerCoefficient {
+    for (a, b) in p.iter().zip(q.iter()) {
+        match Ord::cmp(&a.0, &b.0) {
+            Ordering::Equal => {}
+            v => return v,
+        }
+    }
+    Ord::cmp(&p.len(), &q.len())
+}
@@ -2,8 +2,13 @@ use traits::*;
 use monomial::Monomial;
 use polynomial::Polynomial;
 use composite::Composite;
-use std::collections::HashMap;
+use condition::{Condition, Relation};
+use assumptions::Assumptions;
+use registry::FnId;
+use std::collections::{HashMap, HashSet};
 use std::convert::AsRef;
+use std::rc::Rc;
+use num::rational::Ratio;
 
 /// Returns a polynomial representing 1 * x^1 + 0,
 /// where 'x' is a variable uniquely identifiable by the provided `id`.
@@ -22,7 +27,201 @@ pub fn variable<I, C, P>(id: I) -> Polynomial<I, C, P>
 }
 
 /// Computes a symbolic `max` between two polynomials.
+///
+/// Nested maximums are flattened, terms common to all arguments are pulled out
+/// (`max(a + c, b + c) = max(a, b) + c`) and any argument which is known
+/// to be no larger than another one is dropped (`max(x, x + 3) = x + 3`).
 pub fn max<I, C, P, T1, T2>(left: T1, right: T2) -> Polynomial<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power,
+          T1: AsRef<Polynomial<I, C, P>>,
+          T2: AsRef<Polynomial<I, C, P>> {
+    max_with(left, right, &Assumptions::new())
+}
+
+/// Computes a symbolic `max` between two polynomials under the given assumptions,
+/// e.g. `max(a, 0) = a` when `a` is assumed to be positive.
+pub fn max_with<I, C, P, T1, T2>(left: T1,
+                                 right: T2,
+                                 assumptions: &Assumptions<I, C>)
+                                 -> Polynomial<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power,
+          T1: AsRef<Polynomial<I, C, P>>,
+          T2: AsRef<Polynomial<I, C, P>> {
+    extremum(left.as_ref(), right.as_ref(), true, assumptions)
+}
+
+/// Computes a symbolic `min` between two polynomials.
+///
+/// Nested minimums are flattened, terms common to all arguments are pulled out
+/// (`min(a + c, b + c) = min(a, b) + c`) and any argument which is known
+/// to be no smaller than another one is dropped (`min(x, x + 3) = x`).
+pub fn min<I, C, P, T1, T2>(left: T1, right: T2) -> Polynomial<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power,
+          T1: AsRef<Polynomial<I, C, P>>,
+          T2: AsRef<Polynomial<I, C, P>> {
+    min_with(left, right, &Assumptions::new())
+}
+
+/// Computes a symbolic `min` between two polynomials under the given assumptions,
+/// e.g. `min(a, 0) = 0` when `a` is assumed to be positive.
+pub fn min_with<I, C, P, T1, T2>(left: T1,
+                                 right: T2,
+                                 assumptions: &Assumptions<I, C>)
+                                 -> Polynomial<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power,
+          T1: AsRef<Polynomial<I, C, P>>,
+          T2: AsRef<Polynomial<I, C, P>> {
+    extremum(left.as_ref(), right.as_ref(), false, assumptions)
+}
+
+/// Computes either the `max` (if `maximum` is true) or the `min` between two polynomials.
+fn extremum<I, C, P>(left: &Polynomial<I, C, P>,
+                     right: &Polynomial<I, C, P>,
+                     maximum: bool,
+                     assumptions: &Assumptions<I, C>)
+                     -> Polynomial<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    let mut arguments = Vec::new();
+    flatten_extremum(left, maximum, &mut arguments);
+    flatten_extremum(right, maximum, &mut arguments);
+    // Pull out the monomials common to all of the arguments
+    let mut common = Polynomial { monomials: Vec::new() };
+    for m in &arguments[0].monomials {
+        if arguments[1..].iter().all(|arg| arg.monomials.contains(m)) {
+            common += m;
+        }
+    }
+    // Drop any argument made redundant by another one
+    let mut kept: Vec<Polynomial<I, C, P>> = Vec::new();
+    for arg in arguments {
+        let arg = arg - &common;
+        let redundant = |x: &Polynomial<I, C, P>, y: &Polynomial<I, C, P>| if maximum {
+            at_most(x, y, assumptions)
+        } else {
+            at_most(y, x, assumptions)
+        };
+        if kept.iter().any(|k| redundant(&arg, k)) {
+            continue;
+        }
+        kept.retain(|k| !redundant(k, &arg));
+        kept.push(arg);
+    }
+    if kept.len() == 1 {
+        return kept.pop().unwrap() + common;
+    }
+    // Commutative arguments are kept in a canonical order
+    kept.sort_by(|a, b| Ord::cmp(b, a));
+    let arguments = kept.into_iter().map(Rc::new).collect();
+    let c = if maximum {
+        Composite::Max(arguments)
+    } else {
+        Composite::Min(arguments)
+    };
+    Polynomial {
+        monomials: vec![
+            Monomial {
+                coefficient: C::one(),
+                powers: vec![(c, P::one())],
+            },
+        ],
+    } + common
+}
+
+/// Collects the arguments of the polynomial if it is a `max` (or a `min`) plus some other
+/// terms `r`, distributing them as `max(x, y) + r = max(x + r, y + r)`.
+fn flatten_extremum<I, C, P>(polynomial: &Polynomial<I, C, P>,
+                             maximum: bool,
+                             arguments: &mut Vec<Polynomial<I, C, P>>)
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    for (i, m) in polynomial.monomials.iter().enumerate() {
+        if m.coefficient != C::one() || m.powers.len() != 1 || m.powers[0].1 != P::one() {
+            continue;
+        }
+        match (&m.powers[0].0, maximum) {
+            (Composite::Max(args), true) |
+            (Composite::Min(args), false) => {
+                let mut rest = polynomial.clone();
+                rest.monomials.remove(i);
+                for arg in args {
+                    flatten_extremum(&(&**arg + &rest), maximum, arguments);
+                }
+                return;
+            }
+            _ => {}
+        }
+    }
+    arguments.push(polynomial.clone());
+}
+
+/// Checks whether `x <= y` for any values of the variables allowed by the assumptions.
+fn at_most<I, C, P>(x: &Polynomial<I, C, P>,
+                    y: &Polynomial<I, C, P>,
+                    assumptions: &Assumptions<I, C>)
+                    -> bool
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    if is_non_negative(&(y - x), assumptions) {
+        return true;
+    }
+    // Since min(a, b) <= a and a <= max(a, b)
+    if let Some(Composite::Min(args)) = single_composite(x) {
+        if args.iter().any(|arg| at_most(arg, y, assumptions)) {
+            return true;
+        }
+    }
+    if let Some(Composite::Max(args)) = single_composite(y) {
+        if args.iter().any(|arg| at_most(x, arg, assumptions)) {
+            return true;
+        }
+    }
+    false
+}
+
+/// Returns the composite `c` if the polynomial is exactly `c`.
+fn single_composite<I, C, P>(polynomial: &Polynomial<I, C, P>) -> Option<&Composite<I, C, P>>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    match polynomial.monomials.as_slice() {
+        [m] if m.coefficient == C::one() && m.powers.len() == 1 && m.powers[0].1 == P::one() => {
+            Some(&m.powers[0].0)
+        }
+        _ => None,
+    }
+}
+
+/// Computes a symbolic `ceil` between two polynomials.
+///
+/// For a known positive `right` this is rewritten as `floor(left + right - 1, right)`,
+/// otherwise any terms of `left` divisible by `right` are extracted.
+pub fn ceil<I, C, P, T1, T2>(left: T1, right: T2) -> Polynomial<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power,
+          T1: AsRef<Polynomial<I, C, P>>,
+          T2: AsRef<Polynomial<I, C, P>> {
+    ceil_with(left, right, &Assumptions::new())
+}
+
+/// Computes a symbolic `ceil` between two polynomials under the given assumptions,
+/// e.g. `ceil(a + 3, 8) = floor(a, 8) + 1` when `a` is assumed to be a multiple of 8.
+pub fn ceil_with<I, C, P, T1, T2>(left: T1,
+                                  right: T2,
+                                  assumptions: &Assumptions<I, C>)
+                                  -> Polynomial<I, C, P>
     where I: Id,
           C: Coefficient,
           P: Power,
@@ -33,29 +232,55 @@ pub fn max<I, C, P, T1, T2>(left: T1, right: T2) -> Polynomial<I, C, P>
     if left.is_constant() && right.is_constant() {
         let v1 = left.eval(&HashMap::default()).ok().unwrap();
         let v2 = right.eval(&HashMap::default()).ok().unwrap();
-        Polynomial::from(if v1 > v2 { v1 } else { v2 })
+        Polynomial::from(-(-v1).floor_div(&v2))
+    } else if is_integral(left) && is_integral(right) && is_positive(right, assumptions) {
+        floor_with(left + right - C::one(), right, assumptions)
     } else {
-        Polynomial {
-            monomials: vec![
-                Monomial {
-                    coefficient: C::one(),
-                    powers: vec![
-                        (
-                            Composite::Max(
-                                ::std::rc::Rc::new(left.clone()),
-                                ::std::rc::Rc::new(right.clone()),
+        let (result, reminder) = divide_terms(left, right);
+        if reminder.monomials.is_empty() {
+            result
+        } else if !result.monomials.is_empty() {
+            add_folded(result, ceil_with(reminder, right, assumptions))
+        } else {
+            Polynomial {
+                monomials: vec![
+                    Monomial {
+                        coefficient: C::one(),
+                        powers: vec![
+                            (
+                                Composite::Ceil(Rc::new(left.clone()), Rc::new(right.clone())),
+                                P::one(),
                             ),
-                            P::one(),
-                        ),
-                    ],
-                },
-            ],
+                        ],
+                    },
+                ],
+            }
         }
     }
 }
 
-/// Computes a symbolic `min` between two polynomials.
-pub fn min<I, C, P, T1, T2>(left: T1, right: T2) -> Polynomial<I, C, P>
+/// Computes a symbolic `floor` between two polynomials.
+///
+/// Any terms of `left` divisible by `right` are extracted (`floor(x + k*b, b) = floor(x, b) + k`)
+/// and nested floors with known positive divisors are merged
+/// (`floor(floor(x, a), b) = floor(x, a*b)`).
+pub fn floor<I, C, P, T1, T2>(left: T1, right: T2) -> Polynomial<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power,
+          T1: AsRef<Polynomial<I, C, P>>,
+          T2: AsRef<Polynomial<I, C, P>> {
+    floor_with(left, right, &Assumptions::new())
+}
+
+/// Computes a symbolic `floor` between two polynomials under the given assumptions.
+///
+/// For a constant `right` any terms of `left` known to be its multiples are separated
+/// from the rest, e.g. `floor(a + 3, 8) = floor(a, 8)` when `a` is assumed to be a multiple of 8.
+pub fn floor_with<I, C, P, T1, T2>(left: T1,
+                                   right: T2,
+                                   assumptions: &Assumptions<I, C>)
+                                   -> Polynomial<I, C, P>
     where I: Id,
           C: Coefficient,
           P: Power,
@@ -66,59 +291,559 @@ pub fn min<I, C, P, T1, T2>(left: T1, right: T2) -> Polynomial<I, C, P>
     if left.is_constant() && right.is_constant() {
         let v1 = left.eval(&HashMap::default()).ok().unwrap();
         let v2 = right.eval(&HashMap::default()).ok().unwrap();
-        Polynomial::from(if v1 < v2 { v1 } else { v2 })
+        return Polynomial::from(v1.floor_div(&v2));
+    }
+    let (result, reminder) = divide_terms(left, right);
+    if reminder.monomials.is_empty() {
+        return result;
+    } else if !result.monomials.is_empty() {
+        return add_folded(result, floor_with(reminder, right, assumptions));
+    }
+    // Since floor(x + r, d) = floor(x, d) + floor(r, d) when x is a multiple of d
+    if right.is_constant() && is_positive(right, assumptions) {
+        let d = &right.monomials[0].coefficient;
+        let (multiple, rest): (Vec<_>, Vec<_>) = left.monomials
+            .iter()
+            .cloned()
+            .partition(|m| known_divisor(m, assumptions).floor_mod(d) == C::zero());
+        if !multiple.is_empty() && !rest.is_empty() {
+            return add_folded(
+                floor_with(Polynomial { monomials: multiple }, right, assumptions),
+                floor_with(Polynomial { monomials: rest }, right, assumptions),
+            );
+        }
+    }
+    // Since floor(k*x, k*y) = floor(x, y) for any positive k
+    let common = content(left).greatest_common_divisor(&content(right));
+    if common > C::one() {
+        return floor_with(left / common.clone(), right / common, assumptions);
+    }
+    if is_integral(right) && is_positive(right, assumptions) {
+        // Since floor(floor(x, a) + k, b) = floor(floor(x + k*a, a), b) = floor(x + k*a, a*b)
+        for (i, m) in left.monomials.iter().enumerate() {
+            if m.coefficient != C::one() || m.powers.len() != 1 || m.powers[0].1 != P::one() {
+                continue;
+            }
+            if let Composite::Floor(ref x, ref a) = m.powers[0].0 {
+                let mut rest = left.clone();
+                rest.monomials.remove(i);
+                if is_integral(&rest) && is_positive(a, assumptions) {
+                    return floor_with(&**x + &rest * &**a, &**a * right, assumptions);
+                }
+            }
+        }
+    }
+    Polynomial {
+        monomials: vec![
+            Monomial {
+                coefficient: C::one(),
+                powers: vec![
+                    (
+                        Composite::Floor(Rc::new(left.clone()), Rc::new(right.clone())),
+                        P::one(),
+                    ),
+                ],
+            },
+        ],
+    }
+}
+
+/// Adds two partial results, either of which can be a constant folded to zero
+/// (e.g. `floor(3, 8)`), without keeping the zero as a term of the sum.
+fn add_folded<I, C, P>(left: Polynomial<I, C, P>, right: Polynomial<I, C, P>) -> Polynomial<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    if left == C::zero() {
+        right
+    } else if right == C::zero() {
+        left
+    } else {
+        left + right
+    }
+}
+
+/// Divides `left` by `right` term by term, returning the quotient and the remaining terms
+/// which are not divisible, such that `left = quotient * right + remainder`.
+///
+/// For a positive constant `right` every coefficient is divided with flooring,
+/// leaving all of the coefficients of the remainder in `[0, right)`.
+fn divide_terms<I, C, P>(left: &Polynomial<I, C, P>,
+                         right: &Polynomial<I, C, P>)
+                         -> (Polynomial<I, C, P>, Polynomial<I, C, P>)
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    let mut quotient = Polynomial { monomials: Vec::new() };
+    let mut remainder = Polynomial { monomials: Vec::new() };
+    if right.is_constant() && is_positive(right, &Assumptions::new()) {
+        let d = right.monomials[0].coefficient.clone();
+        for m in &left.monomials {
+            let (q, r) = (m.coefficient.floor_div(&d), m.coefficient.floor_mod(&d));
+            if q != C::zero() {
+                quotient += &Monomial {
+                    coefficient: q,
+                    powers: m.powers.clone(),
+                };
+            }
+            if r != C::zero() {
+                remainder += &Monomial {
+                    coefficient: r,
+                    powers: m.powers.clone(),
+                };
+            }
+        }
+    } else if !right.monomials.is_empty() {
+        let mut left = left.clone();
+        while !left.monomials.is_empty() {
+            match left.monomials[0].checked_div(&right.monomials[0]) {
+                Some(ref x) if x.coefficient.floor_mod(&C::one()) == C::zero() => {
+                    quotient += x;
+                    left -= &(right * x);
+                }
+                _ => {
+                    let m = left.monomials.remove(0);
+                    remainder += &m;
+                }
+            }
+        }
+    } else {
+        remainder = left.clone();
+    }
+    (quotient, remainder)
+}
+
+/// Computes a symbolic `mod` between two polynomials, with the result
+/// having the same sign as `right` (e.g. `left - right * floor(left, right)`).
+pub fn modulo<I, C, P, T1, T2>(left: T1, right: T2) -> Polynomial<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power,
+          T1: AsRef<Polynomial<I, C, P>>,
+          T2: AsRef<Polynomial<I, C, P>> {
+    let left = left.as_ref();
+    let right = right.as_ref();
+    if left.is_constant() && right.is_constant() {
+        let v1 = left.eval(&HashMap::default()).ok().unwrap();
+        let v2 = right.eval(&HashMap::default()).ok().unwrap();
+        Polynomial::from(v1.floor_mod(&v2))
+    } else {
+        let (result, reminder) = left.div_rem(right);
+        if reminder.monomials.is_empty() && is_integral(&result) {
+            Polynomial { monomials: Vec::new() }
+        } else {
+            Polynomial {
+                monomials: vec![
+                    Monomial {
+                        coefficient: C::one(),
+                        powers: vec![
+                            (
+                                Composite::Mod(Rc::new(left.clone()), Rc::new(right.clone())),
+                                P::one(),
+                            ),
+                        ],
+                    },
+                ],
+            }
+        }
+    }
+}
+
+/// Computes a symbolic division between two polynomials, which rounds towards zero
+/// (e.g. the integer division in C).
+pub fn trunc_div<I, C, P, T1, T2>(left: T1, right: T2) -> Polynomial<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power,
+          T1: AsRef<Polynomial<I, C, P>>,
+          T2: AsRef<Polynomial<I, C, P>> {
+    let left = left.as_ref();
+    let right = right.as_ref();
+    let c = Composite::TruncDiv(Rc::new(left.clone()), Rc::new(right.clone()));
+    division(c, left, right)
+}
+
+/// Computes a symbolic division between two polynomials, which rounds to the nearest
+/// integer with halfway cases rounded away from zero (e.g. `round` in C).
+pub fn round_div<I, C, P, T1, T2>(left: T1, right: T2) -> Polynomial<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power,
+          T1: AsRef<Polynomial<I, C, P>>,
+          T2: AsRef<Polynomial<I, C, P>> {
+    let left = left.as_ref();
+    let right = right.as_ref();
+    let c = Composite::RoundDiv(Rc::new(left.clone()), Rc::new(right.clone()));
+    division(c, left, right)
+}
+
+/// Simplifies the composite division `c` of `left` by `right`, for which
+/// all rounding modes agree whenever the division is exact.
+fn division<I, C, P>(c: Composite<I, C, P>,
+                     left: &Polynomial<I, C, P>,
+                     right: &Polynomial<I, C, P>)
+                     -> Polynomial<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    if left.is_constant() && right.is_constant() {
+        Polynomial::from(c.eval(&HashMap::default()).ok().unwrap())
+    } else {
+        let (result, reminder) = left.div_rem(right);
+        if reminder.monomials.is_empty() && is_integral(&result) {
+            result
+        } else {
+            Polynomial {
+                monomials: vec![
+                    Monomial {
+                        coefficient: C::one(),
+                        powers: vec![(c, P::one())],
+                    },
+                ],
+            }
+        }
+    }
+}
+
+/// Computes a symbolic absolute value of the polynomial.
+pub fn abs<I, C, P, T>(polynomial: T) -> Polynomial<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power,
+          T: AsRef<Polynomial<I, C, P>> {
+    let polynomial = polynomial.as_ref();
+    let assumptions = Assumptions::new();
+    if polynomial.is_constant() {
+        let v = polynomial.eval(&HashMap::default()).ok().unwrap();
+        Polynomial::from(if v < C::zero() { -v } else { v })
+    } else if is_non_negative(polynomial, &assumptions) {
+        polynomial.clone()
+    } else if is_non_negative(&-polynomial, &assumptions) ||
+              polynomial.monomials[0].coefficient < C::zero() {
+        // Since |x| = |-x| the argument is kept with a positive leading coefficient
+        abs(-polynomial)
+    } else if content(polynomial) > C::one() {
+        let content = content(polynomial);
+        abs(polynomial / content.clone()) * content
     } else {
         Polynomial {
             monomials: vec![
                 Monomial {
                     coefficient: C::one(),
-                    powers: vec![
-                        (
-                            Composite::Min(
-                                ::std::rc::Rc::new(left.clone()),
-                                ::std::rc::Rc::new(right.clone()),
-                            ),
-                            P::one(),
-                        ),
-                    ],
+                    powers: vec![(Composite::Abs(Rc::new(polynomial.clone())), P::one())],
+                },
+            ],
+        }
+    }
+}
+
+/// Computes a symbolic sign of the polynomial, which is one of `-1`, `0` or `1`.
+pub fn sign<I, C, P, T>(polynomial: T) -> Polynomial<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power,
+          T: AsRef<Polynomial<I, C, P>> {
+    let polynomial = polynomial.as_ref();
+    if polynomial.is_constant() {
+        let v = polynomial.eval(&HashMap::default()).ok().unwrap();
+        Polynomial::from(if v > C::zero() {
+            C::one()
+        } else if v < C::zero() {
+            -C::one()
+        } else {
+            C::zero()
+        })
+    } else if is_positive(polynomial, &Assumptions::new()) {
+        Polynomial::from(C::one())
+    } else if polynomial.monomials[0].coefficient < C::zero() {
+        // Since sign(x) = -sign(-x) the argument is kept with a positive leading coefficient
+        -sign(-polynomial)
+    } else if content(polynomial) > C::one() {
+        sign(polynomial / content(polynomial))
+    } else {
+        Polynomial {
+            monomials: vec![
+                Monomial {
+                    coefficient: C::one(),
+                    powers: vec![(Composite::Sign(Rc::new(polynomial.clone())), P::one())],
                 },
             ],
         }
     }
 }
 
-/// Computes a symbolic `ceil` between two polynomials.
-pub fn ceil<I, C, P, T1, T2>(left: T1, right: T2) -> Polynomial<I, C, P>
+/// Computes a symbolic greatest common divisor of two polynomials, which is non-negative.
+///
+/// Nested divisors are flattened, any monomial factor common to all arguments is extracted
+/// (`gcd(k*a, k*b) = |k| * gcd(a, b)`) and any argument which is a multiple of another one
+/// is dropped (`gcd(a, a*b) = |a|`).
+pub fn gcd<I, C, P, T1, T2>(left: T1, right: T2) -> Polynomial<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power,
+          T1: AsRef<Polynomial<I, C, P>>,
+          T2: AsRef<Polynomial<I, C, P>> {
+    divisor_or_multiple(left.as_ref(), right.as_ref(), true)
+}
+
+/// Computes a symbolic least common multiple of two polynomials, which is non-negative.
+///
+/// Nested multiples are flattened, any monomial factor common to all arguments is extracted
+/// (`lcm(k*a, k*b) = |k| * lcm(a, b)`) and any argument which divides another one
+/// is dropped (`lcm(a, a*b) = |a*b|`).
+pub fn lcm<I, C, P, T1, T2>(left: T1, right: T2) -> Polynomial<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power,
+          T1: AsRef<Polynomial<I, C, P>>,
+          T2: AsRef<Polynomial<I, C, P>> {
+    divisor_or_multiple(left.as_ref(), right.as_ref(), false)
+}
+
+/// Computes either the `gcd` (if `greatest` is true) or the `lcm` of two polynomials.
+fn divisor_or_multiple<I, C, P>(left: &Polynomial<I, C, P>,
+                                right: &Polynomial<I, C, P>,
+                                greatest: bool)
+                                -> Polynomial<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    let mut arguments = Vec::new();
+    for polynomial in &[left, right] {
+        match single_composite(polynomial) {
+            Some(Composite::Gcd(args)) if greatest => {
+                arguments.extend(args.iter().map(|arg| (**arg).clone()))
+            }
+            Some(Composite::Lcm(args)) if !greatest => {
+                arguments.extend(args.iter().map(|arg| (**arg).clone()))
+            }
+            _ => arguments.push((*polynomial).clone()),
+        }
+    }
+    // Since gcd(x, 0) = |x| and lcm(x, 0) = 0
+    if greatest {
+        arguments.retain(|arg| !arg.monomials.is_empty());
+        if arguments.is_empty() {
+            return Polynomial { monomials: Vec::new() };
+        }
+    } else if arguments.iter().any(|arg| arg.monomials.is_empty()) {
+        return Polynomial { monomials: Vec::new() };
+    }
+    if arguments.iter().all(|arg| arg.is_constant()) {
+        let arguments = arguments.into_iter().map(Rc::new).collect();
+        let c = if greatest {
+            Composite::Gcd(arguments)
+        } else {
+            Composite::Lcm(arguments)
+        };
+        return Polynomial::from(c.eval(&HashMap::default()).ok().unwrap());
+    }
+    let mut factor = common_factor(&arguments);
+    // Divisibility is not decided by Laurent division, hence with negative powers only the
+    // coefficients are factored out and no argument is dropped
+    let laurent = arguments.iter().any(|arg| arg.monomials.iter().any(|m| m.has_negative_powers()));
+    if laurent {
+        factor.monomials[0].powers.clear();
+    }
+    // Drop any argument made redundant by another one
+    let mut kept: Vec<Polynomial<I, C, P>> = Vec::new();
+    for arg in &arguments {
+        let arg = cofactor(arg, &factor);
+        // With rational coefficients only an integral quotient makes one a multiple of the other
+        let multiple = |x: &Polynomial<I, C, P>, y: &Polynomial<I, C, P>| {
+            x.checked_div(y).is_some_and(|q| is_integral(&q))
+        };
+        let redundant = |x: &Polynomial<I, C, P>, y: &Polynomial<I, C, P>| if greatest {
+            multiple(x, y)
+        } else {
+            multiple(y, x)
+        };
+        if !laurent {
+            if kept.iter().any(|k| redundant(&arg, k)) {
+                continue;
+            }
+            kept.retain(|k| !redundant(k, &arg));
+        }
+        kept.push(arg);
+    }
+    if kept.len() == 1 {
+        return abs(factor * kept.pop().unwrap());
+    }
+    // Commutative arguments are kept in a canonical order
+    kept.sort_by(|a, b| Ord::cmp(b, a));
+    let arguments = kept.into_iter().map(Rc::new).collect();
+    let c = if greatest {
+        Composite::Gcd(arguments)
+    } else {
+        Composite::Lcm(arguments)
+    };
+    abs(factor) *
+    &Monomial {
+        coefficient: C::one(),
+        powers: vec![(c, P::one())],
+    }
+}
+
+/// Computes the greatest monomial factor common to all of the non-zero polynomials.
+fn common_factor<I, C, P>(polynomials: &[Polynomial<I, C, P>]) -> Polynomial<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    let mut monomials = polynomials.iter().flat_map(|p| p.monomials.iter());
+    let first = monomials.next().unwrap();
+    let mut factor = Monomial {
+        coefficient: C::zero().greatest_common_divisor(&first.coefficient),
+        powers: first.powers.clone(),
+    };
+    for m in monomials {
+        factor.coefficient = factor.coefficient.greatest_common_divisor(&m.coefficient);
+        factor.powers = factor.powers
+            .iter()
+            .filter_map(|(c, p)| {
+                m.powers.iter().find(|&(o_c, _)| o_c == c).map(|(_, o_p)| {
+                    (c.clone(), if p < o_p { p.clone() } else { o_p.clone() })
+                })
+            })
+            .collect();
+    }
+    Polynomial::from(factor)
+}
+
+/// Divides the polynomial by the `factor`, normalizing the result to have
+/// a positive leading coefficient.
+fn cofactor<I, C, P>(polynomial: &Polynomial<I, C, P>,
+                     factor: &Polynomial<I, C, P>)
+                     -> Polynomial<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    let result = polynomial.checked_div(factor).unwrap();
+    if result.monomials[0].coefficient < C::zero() {
+        -result
+    } else {
+        result
+    }
+}
+
+/// Computes a symbolic power of two `2^exponent`.
+///
+/// Any positive constant term of the exponent is evaluated into the coefficient,
+/// e.g. `2^(a + 3) = 8 * 2^a`.
+pub fn exp2<I, C, P, T>(exponent: T) -> Polynomial<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power,
+          T: AsRef<Polynomial<I, C, P>> {
+    let exponent = exponent.as_ref();
+    let c = Composite::Exp2(Rc::new(exponent.clone()));
+    if let Ok(value) = c.eval(&HashMap::default()) {
+        return Polynomial::from(value);
+    }
+    match exponent.monomials.last() {
+        Some(m) if m.powers.is_empty() && m.coefficient > C::zero() &&
+                   m.coefficient.as_usize().is_some() => {
+            exp2(exponent - m.coefficient.clone()) *
+            ::num::pow(C::one() + C::one(), m.coefficient.as_usize().unwrap())
+        }
+        _ => {
+            Polynomial {
+                monomials: vec![
+                    Monomial {
+                        coefficient: C::one(),
+                        powers: vec![(c, P::one())],
+                    },
+                ],
+            }
+        }
+    }
+}
+
+/// Computes a symbolic `floor(log2(x))`.
+pub fn log2_floor<I, C, P, T>(polynomial: T) -> Polynomial<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power,
+          T: AsRef<Polynomial<I, C, P>> {
+    let polynomial = polynomial.as_ref();
+    let c = Composite::Log2Floor(Rc::new(polynomial.clone()));
+    logarithm(c, polynomial)
+}
+
+/// Computes a symbolic `ceil(log2(x))`.
+pub fn log2_ceil<I, C, P, T>(polynomial: T) -> Polynomial<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power,
+          T: AsRef<Polynomial<I, C, P>> {
+    let polynomial = polynomial.as_ref();
+    let c = Composite::Log2Ceil(Rc::new(polynomial.clone()));
+    logarithm(c, polynomial)
+}
+
+/// Simplifies the composite logarithm `c` of the `polynomial`, using the identity
+/// `log2(k * 2^n) = n + log2(k)` for a positive constant `k`.
+fn logarithm<I, C, P>(c: Composite<I, C, P>,
+                      polynomial: &Polynomial<I, C, P>)
+                      -> Polynomial<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    if let Ok(value) = c.eval(&HashMap::default()) {
+        return Polynomial::from(value);
+    }
+    if polynomial.monomials.len() == 1 && polynomial.monomials[0].coefficient > C::zero() {
+        let m = &polynomial.monomials[0];
+        if let [(Composite::Exp2(ref exponent), ref p)] = m.powers[..] {
+            let coefficient = Rc::new(Polynomial::<I, C, P>::from(m.coefficient.clone()));
+            let log = match c {
+                Composite::Log2Ceil(_) => Composite::Log2Ceil(coefficient),
+                _ => Composite::Log2Floor(coefficient),
+            };
+            return &**exponent * C::from_i64(p.to_i64().unwrap()).unwrap() +
+                   log.eval(&HashMap::default()).unwrap();
+        }
+    }
+    Polynomial {
+        monomials: vec![
+            Monomial {
+                coefficient: C::one(),
+                powers: vec![(c, P::one())],
+            },
+        ],
+    }
+}
+
+/// Computes a symbolic piecewise selection, which is equal to `then` if the `condition`
+/// holds and to `otherwise` if it does not.
+///
+/// The selection is resolved whenever the condition can be decided symbolically,
+/// e.g. `select(a^2 + 1 > 0, x, y) = x`.
+pub fn select<I, C, P, T1, T2>(condition: Condition<I, C, P>,
+                               then: T1,
+                               otherwise: T2)
+                               -> Polynomial<I, C, P>
     where I: Id,
           C: Coefficient,
           P: Power,
           T1: AsRef<Polynomial<I, C, P>>,
           T2: AsRef<Polynomial<I, C, P>> {
-    let left = left.as_ref();
-    let right = right.as_ref();
-    if left.is_constant() && right.is_constant() {
-        let v1 = left.eval(&HashMap::default()).ok().unwrap();
-        let v2 = right.eval(&HashMap::default()).ok().unwrap();
-        let (d, rem) = v1.div_rem(&v2);
-        if rem == C::zero() {
-            Polynomial::from(d)
-        } else {
-            Polynomial::from(d + C::one())
-        }
-    } else {
-        let (result, reminder) = left.div_rem(right);
-        if reminder.monomials.is_empty() {
-            result
-        } else {
+    let then = then.as_ref();
+    let otherwise = otherwise.as_ref();
+    match decide(&condition) {
+        Some(true) => then.clone(),
+        Some(false) => otherwise.clone(),
+        None if then == otherwise => then.clone(),
+        None => {
             Polynomial {
                 monomials: vec![
                     Monomial {
                         coefficient: C::one(),
                         powers: vec![
                             (
-                                Composite::Ceil(
-                                    ::std::rc::Rc::new(left.clone()),
-                                    ::std::rc::Rc::new(right.clone()),
+                                Composite::Select(
+                                    Rc::new(condition),
+                                    Rc::new(then.clone()),
+                                    Rc::new(otherwise.clone()),
                                 ),
                                 P::one(),
                             ),
@@ -130,42 +855,198 @@ pub fn ceil<I, C, P, T1, T2>(left: T1, right: T2) -> Polynomial<I, C, P>
     }
 }
 
-/// Computes a symbolic `floor` between two polynomials.
-pub fn floor<I, C, P, T1, T2>(left: T1, right: T2) -> Polynomial<I, C, P>
+/// Computes a symbolic application of the user-defined function `id` to the `arguments`.
+///
+/// The application is kept symbolic even if all of the arguments are constant, such that
+/// the result does not depend on which functions are registered at the time. It is only
+/// evaluated through the registry by `eval` and `reduce`.
+pub fn apply<I, C, P, T>(id: FnId, arguments: &[T]) -> Polynomial<I, C, P>
     where I: Id,
           C: Coefficient,
           P: Power,
-          T1: AsRef<Polynomial<I, C, P>>,
-          T2: AsRef<Polynomial<I, C, P>> {
-    let left = left.as_ref();
-    let right = right.as_ref();
-    if left.is_constant() && right.is_constant() {
-        let v1 = left.eval(&HashMap::default()).ok().unwrap();
-        let v2 = right.eval(&HashMap::default()).ok().unwrap();
-        Polynomial::from(C::div_floor(&v1, &v2))
-    } else {
-        let (result, reminder) = left.div_rem(right);
-        if reminder.monomials.is_empty() {
-            result
-        } else {
-            Polynomial {
-                monomials: vec![
-                    Monomial {
-                        coefficient: C::one(),
-                        powers: vec![
-                            (
-                                Composite::Floor(
-                                    ::std::rc::Rc::new(left.clone()),
-                                    ::std::rc::Rc::new(right.clone()),
-                                ),
-                                P::one(),
-                            ),
-                        ],
-                    },
-                ],
+          T: AsRef<Polynomial<I, C, P>> {
+    let arguments = arguments.iter().map(|arg| Rc::new(arg.as_ref().clone())).collect();
+    Polynomial {
+        monomials: vec![
+            Monomial {
+                coefficient: C::one(),
+                powers: vec![(Composite::Apply(id, arguments), P::one())],
+            },
+        ],
+    }
+}
+
+/// Tries to decide the condition for any values of its variables,
+/// based on the sign of the difference between the two sides.
+fn decide<I, C, P>(condition: &Condition<I, C, P>) -> Option<bool>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    let difference = &condition.left - &condition.right;
+    if difference.is_constant() {
+        let value = difference.eval(&HashMap::default()).ok().unwrap();
+        return Some(condition.relation.holds(&value, &C::zero()));
+    }
+    let negated = -&difference;
+    let assumptions = Assumptions::new();
+    match condition.relation {
+        Relation::Equal | Relation::NotEqual => {
+            let different = is_positive(&difference, &assumptions) ||
+                            is_positive(&negated, &assumptions);
+            if different {
+                Some(condition.relation == Relation::NotEqual)
+            } else {
+                None
             }
         }
+        Relation::Greater if is_positive(&difference, &assumptions) => Some(true),
+        Relation::GreaterOrEqual if is_non_negative(&difference, &assumptions) => Some(true),
+        Relation::Less if is_positive(&negated, &assumptions) => Some(true),
+        Relation::LessOrEqual if is_non_negative(&negated, &assumptions) => Some(true),
+        Relation::Greater if is_non_negative(&negated, &assumptions) => Some(false),
+        Relation::GreaterOrEqual if is_positive(&negated, &assumptions) => Some(false),
+        Relation::Less if is_non_negative(&difference, &assumptions) => Some(false),
+        Relation::LessOrEqual if is_positive(&difference, &assumptions) => Some(false),
+        _ => None,
+    }
+}
+
+/// Computes the greatest common divisor of all of the coefficients of the polynomial.
+fn content<I, C, P>(polynomial: &Polynomial<I, C, P>) -> C
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    polynomial.monomials
+        .iter()
+        .fold(C::zero(), |acc, m| acc.greatest_common_divisor(&m.coefficient))
+}
+
+/// Checks whether all of the coefficients of the polynomial are integers, which makes it
+/// integer valued as the variables are integers as well.
+fn is_integral<I, C, P>(polynomial: &Polynomial<I, C, P>) -> bool
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    polynomial.monomials.iter().all(|m| m.coefficient.floor_mod(&C::one()) == C::zero())
+}
+
+/// Checks whether the polynomial is non-negative for any values of its variables
+/// allowed by the assumptions.
+pub(crate) fn is_non_negative<I, C, P>(polynomial: &Polynomial<I, C, P>,
+                            assumptions: &Assumptions<I, C>)
+                            -> bool
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    let (polynomial, non_negative) = shift_bounded(polynomial, assumptions);
+    is_non_negative_given(&polynomial, &non_negative)
+}
+
+/// Checks whether the polynomial is strictly positive for any values of its variables
+/// allowed by the assumptions, e.g. it is non-negative with a positive constant term
+/// or a power of two.
+fn is_positive<I, C, P>(polynomial: &Polynomial<I, C, P>, assumptions: &Assumptions<I, C>) -> bool
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    let (polynomial, non_negative) = shift_bounded(polynomial, assumptions);
+    is_non_negative_given(&polynomial, &non_negative) &&
+    polynomial.monomials.iter().any(|m| {
+        m.powers.iter().all(|(c, _)| matches!(*c, Composite::Exp2(_)))
+    })
+}
+
+/// Replaces each variable with an assumed bound by a non-negative one, e.g. `x = y + l`
+/// for a lower bound `l` or `x = u - y` for an upper bound `u` (keeping the same identifier),
+/// returning the result together with all of the replaced variables.
+fn shift_bounded<I, C, P>(polynomial: &Polynomial<I, C, P>,
+                          assumptions: &Assumptions<I, C>)
+                          -> (Polynomial<I, C, P>, HashSet<I>)
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    let mut values = HashMap::new();
+    for id in assumptions.bounded() {
+        // A multiple of k is represented as k times a variable with scaled bounds
+        let k = assumptions.divisor(id);
+        let x: Polynomial<I, C, P> = variable(id.clone());
+        let value = match (assumptions.lower_bound(id), assumptions.upper_bound(id)) {
+            (Some(lower), _) => (x - (-lower.clone()).floor_div(&k)) * k,
+            (None, Some(upper)) => (-x + upper.floor_div(&k)) * k,
+            (None, None) => continue,
+        };
+        values.insert(id.clone(), value);
+    }
+    if values.is_empty() {
+        return (polynomial.clone(), HashSet::new());
+    }
+    let non_negative = values.keys().cloned().collect();
+    (substitute(polynomial, &values), non_negative)
+}
+
+/// Checks whether the polynomial is non-negative when the `non_negative` variables are.
+/// This is a conservative structural check, requiring all of the coefficients to be positive
+/// and each factor to be either non-negative or raised to an even power.
+fn is_non_negative_given<I, C, P>(polynomial: &Polynomial<I, C, P>,
+                                  non_negative: &HashSet<I>)
+                                  -> bool
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    polynomial.monomials.iter().all(|m| {
+        m.coefficient > C::zero() &&
+        m.powers.iter().all(|(c, p)| p.is_even() || is_non_negative_composite(c, non_negative))
+    })
+}
+
+fn is_non_negative_composite<I, C, P>(composite: &Composite<I, C, P>,
+                                      non_negative: &HashSet<I>)
+                                      -> bool
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    let is_non_negative = |x: &Polynomial<I, C, P>| is_non_negative_given(x, non_negative);
+    match *composite {
+        Composite::Variable(ref id) => non_negative.contains(id),
+        Composite::Apply(_, _) => false,
+        Composite::Abs(_) |
+        Composite::Gcd(_) |
+        Composite::Lcm(_) |
+        Composite::Exp2(_) |
+        Composite::Log2Floor(_) |
+        Composite::Log2Ceil(_) => true,
+        Composite::Select(_, ref then, ref otherwise) => {
+            is_non_negative(then) && is_non_negative(otherwise)
+        }
+        Composite::Sign(ref x) => is_non_negative(x),
+        Composite::Max(ref args) => args.iter().any(|arg| is_non_negative(arg)),
+        Composite::Min(ref args) => args.iter().all(|arg| is_non_negative(arg)),
+        Composite::Mod(_, ref right) => is_non_negative(right),
+        Composite::Floor(ref left, ref right) |
+        Composite::Ceil(ref left, ref right) |
+        Composite::TruncDiv(ref left, ref right) |
+        Composite::RoundDiv(ref left, ref right) => {
+            is_non_negative(left) && is_non_negative(right)
+        }
+    }
+}
+
+/// Computes a divisor of the monomial known from its coefficient and the assumptions,
+/// e.g. `24` for `3ab` when `a` is assumed to be a multiple of 8.
+fn known_divisor<I, C, P>(monomial: &Monomial<I, C, P>, assumptions: &Assumptions<I, C>) -> C
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    let mut divisor = monomial.coefficient.clone();
+    if monomial.has_negative_powers() {
+        return C::one();
+    }
+    for (c, p) in &monomial.powers {
+        if let Composite::Variable(ref id) = *c {
+            divisor *= ::num::pow(assumptions.divisor(id), p.to_usize().unwrap());
+        }
     }
+    divisor
 }
 
 /// Reduces the monomial, given the variable assignments provided.
@@ -182,96 +1063,74 @@ pub fn reduce_monomial<I, C, P, T>(monomial: T, values: &HashMap<I, C>) -> Monom
             coefficient: monomial.coefficient.clone(),
             powers: Vec::new(),
         };
-        for &(ref c, ref p) in &monomial.powers {
-            match *c {
+        for (c, p) in &monomial.powers {
+            let c = match *c {
                 Composite::Variable(ref id) => {
                     match values.get(id) {
-                        Some(value) => {
-                            result.coefficient *= ::num::pow(value.clone(), p.to_usize().unwrap());
-                        }
-                        None => {
-                            result *= &Monomial::<I, C, P> {
-                                coefficient: C::one(),
-                                powers: vec![(c.clone(), p.clone())],
-                            };
+                        Some(value) if scale(&mut result.coefficient, value, p) => {
+                            continue;
                         }
+                        _ => c.clone(),
                     }
                 }
-                Composite::Max(ref left, ref right) => {
-                    let mut reduced_left = ::std::rc::Rc::new(reduce(&*left, &*values));
-                    let mut reduced_right = ::std::rc::Rc::new(reduce(&*right, &*values));
-                    if reduced_left.eq(left) {
-                        reduced_left = left.clone();
-                    }
-                    if reduced_right.eq(right) {
-                        reduced_right = right.clone();
-                    }
-                    let c = Composite::Max(reduced_left.clone(), reduced_right.clone());
-                    if reduced_left.is_constant() && reduced_right.is_constant() {
-                        result.coefficient *= c.eval(values).unwrap();
-                    } else {
-                        result *= &Monomial::<I, C, P> {
-                            coefficient: C::one(),
-                            powers: vec![(c, p.clone())],
-                        };
-                    }
-                }
-                Composite::Min(ref left, ref right) => {
-                    let mut reduced_left = ::std::rc::Rc::new(reduce(&*left, &*values));
-                    let mut reduced_right = ::std::rc::Rc::new(reduce(&*right, &*values));
-                    if reduced_left.eq(left) {
-                        reduced_left = left.clone();
-                    }
-                    if reduced_right.eq(right) {
-                        reduced_right = right.clone();
-                    }
-                    let c = Composite::Min(reduced_left.clone(), reduced_right.clone());
-                    if reduced_left.is_constant() && reduced_right.is_constant() {
-                        result.coefficient *= c.eval(values).unwrap();
-                    } else {
-                        result *= &Monomial::<I, C, P> {
-                            coefficient: C::one(),
-                            powers: vec![(c, p.clone())],
-                        };
-                    }
-                }
+                Composite::Max(ref args) => Composite::Max(reduce_commutative(args, values)),
+                Composite::Min(ref args) => Composite::Min(reduce_commutative(args, values)),
                 Composite::Ceil(ref left, ref right) => {
-                    let mut reduced_left = ::std::rc::Rc::new(reduce(&*left, &*values));
-                    let mut reduced_right = ::std::rc::Rc::new(reduce(&*right, &*values));
-                    if reduced_left.eq(left) {
-                        reduced_left = left.clone();
-                    }
-                    if reduced_right.eq(right) {
-                        reduced_right = right.clone();
-                    }
-                    let c = Composite::Ceil(reduced_left.clone(), reduced_right.clone());
-                    if reduced_left.is_constant() && reduced_right.is_constant() {
-                        result.coefficient *= c.eval(values).unwrap();
-                    } else {
-                        result *= &Monomial::<I, C, P> {
-                            coefficient: C::one(),
-                            powers: vec![(c, p.clone())],
-                        };
-                    }
+                    Composite::Ceil(reduce_argument(left, values), reduce_argument(right, values))
                 }
                 Composite::Floor(ref left, ref right) => {
-                    let mut reduced_left = ::std::rc::Rc::new(reduce(&*left, &*values));
-                    let mut reduced_right = ::std::rc::Rc::new(reduce(&*right, &*values));
-                    if reduced_left.eq(left) {
-                        reduced_left = left.clone();
-                    }
-                    if reduced_right.eq(right) {
-                        reduced_right = right.clone();
-                    }
-                    let c = Composite::Floor(reduced_left.clone(), reduced_right.clone());
-                    if reduced_left.is_constant() && reduced_right.is_constant() {
-                        result.coefficient *= c.eval(values).unwrap();
+                    Composite::Floor(reduce_argument(left, values), reduce_argument(right, values))
+                }
+                Composite::Mod(ref left, ref right) => {
+                    Composite::Mod(reduce_argument(left, values), reduce_argument(right, values))
+                }
+                Composite::TruncDiv(ref left, ref right) => {
+                    Composite::TruncDiv(
+                        reduce_argument(left, values),
+                        reduce_argument(right, values),
+                    )
+                }
+                Composite::RoundDiv(ref left, ref right) => {
+                    Composite::RoundDiv(
+                        reduce_argument(left, values),
+                        reduce_argument(right, values),
+                    )
+                }
+                Composite::Abs(ref x) => Composite::Abs(reduce_argument(x, values)),
+                Composite::Sign(ref x) => Composite::Sign(reduce_argument(x, values)),
+                Composite::Gcd(ref args) => Composite::Gcd(reduce_commutative(args, values)),
+                Composite::Lcm(ref args) => Composite::Lcm(reduce_commutative(args, values)),
+                Composite::Exp2(ref x) => Composite::Exp2(reduce_argument(x, values)),
+                Composite::Log2Floor(ref x) => Composite::Log2Floor(reduce_argument(x, values)),
+                Composite::Log2Ceil(ref x) => Composite::Log2Ceil(reduce_argument(x, values)),
+                Composite::Select(ref cond, ref then, ref otherwise) => {
+                    let left = reduce(&cond.left, values);
+                    let right = reduce(&cond.right, values);
+                    let cond = if left == cond.left && right == cond.right {
+                        cond.clone()
                     } else {
-                        result *= &Monomial::<I, C, P> {
-                            coefficient: C::one(),
-                            powers: vec![(c, p.clone())],
-                        };
-                    }
+                        Rc::new(Condition::new(left, cond.relation, right))
+                    };
+                    Composite::Select(
+                        cond,
+                        reduce_argument(then, values),
+                        reduce_argument(otherwise, values),
+                    )
+                }
+                Composite::Apply(ref id, ref args) => {
+                    Composite::Apply(
+                        id.clone(),
+                        args.iter().map(|arg| reduce_argument(arg, values)).collect(),
+                    )
+                }
+            };
+            match c.eval(&HashMap::new()) {
+                Ok(ref value) if scale(&mut result.coefficient, value, p) => {}
+                _ => {
+                    result *= &Monomial::<I, C, P> {
+                        coefficient: C::one(),
+                        powers: vec![(c, p.clone())],
+                    };
                 }
             }
         }
@@ -279,6 +1138,50 @@ pub fn reduce_monomial<I, C, P, T>(monomial: T, values: &HashMap<I, C>) -> Monom
     }
 }
 
+/// Multiplies the coefficient by `value` raised to the power `p`. For a negative power
+/// it is divided instead, which fails without changing it if the division is not exact.
+fn scale<C, P>(coefficient: &mut C, value: &C, p: &P) -> bool
+    where C: Coefficient,
+          P: Power {
+    if *p < P::zero() {
+        let power = ::num::pow(value.clone(), (P::zero() - p.clone()).to_usize().unwrap());
+        match coefficient.exact_div(&power) {
+            Some(d) => *coefficient = d,
+            None => return false,
+        }
+    } else {
+        *coefficient *= ::num::pow(value.clone(), p.to_usize().unwrap());
+    }
+    true
+}
+
+/// Reduces the argument of a composite, reusing the original one if it did not change.
+fn reduce_argument<I, C, P>(argument: &Rc<Polynomial<I, C, P>>,
+                            values: &HashMap<I, C>)
+                            -> Rc<Polynomial<I, C, P>>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    let reduced = reduce(&**argument, values);
+    if reduced.eq(&**argument) {
+        argument.clone()
+    } else {
+        Rc::new(reduced)
+    }
+}
+
+/// Reduces all of the arguments of a commutative composite, keeping them in canonical order.
+fn reduce_commutative<I, C, P>(arguments: &[Rc<Polynomial<I, C, P>>],
+                               values: &HashMap<I, C>)
+                               -> Vec<Rc<Polynomial<I, C, P>>>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    let mut reduced: Vec<_> = arguments.iter().map(|arg| reduce_argument(arg, values)).collect();
+    reduced.sort_by(|a, b| Ord::cmp(b, a));
+    reduced
+}
+
 /// Reduces the polynomial, given the variable assignments provided.
 pub fn reduce<I, C, P, T>(polynomial: T, values: &HashMap<I, C>) -> Polynomial<I, C, P>
     where I: Id,
@@ -288,7 +1191,300 @@ pub fn reduce<I, C, P, T>(polynomial: T, values: &HashMap<I, C>) -> Polynomial<I
     let polynomial = polynomial.as_ref();
     let mut result = Polynomial::<I, C, P> { monomials: Vec::new() };
     for m in &polynomial.monomials {
-        result += &reduce_monomial(m, values);
+        let m = reduce_monomial(m, values);
+        if m.powers.iter().any(|(c, _)| matches!(*c, Composite::Select(_, _, _))) {
+            // A selection might have become decidable, resolving it to one of its branches
+            result += &substitute(Polynomial::from(m), &HashMap::new());
+        } else {
+            result += &m;
+        }
+    }
+    result
+}
+
+/// Reduces the polynomial, given the variable assignments provided, and simplifies
+/// the remaining composites under the given assumptions,
+/// e.g. `floor(a, 8) * 8 = a` when `a` is assumed to be a multiple of 8.
+pub fn reduce_with<I, C, P, T>(polynomial: T,
+                               values: &HashMap<I, C>,
+                               assumptions: &Assumptions<I, C>)
+                               -> Polynomial<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power,
+          T: AsRef<Polynomial<I, C, P>> {
+    let reduced = reduce(polynomial, values);
+    if assumptions.is_empty() {
+        return reduced;
+    }
+    let simplified = substitute_with(&reduced, &HashMap::new(), assumptions);
+    let mut result = Polynomial::<I, C, P> { monomials: Vec::new() };
+    for m in &simplified.monomials {
+        result += &exact_division(m, assumptions);
+    }
+    result
+}
+
+/// Replaces a `d * floor(x, d)` (or a `ceil`) factor of the monomial with `x`
+/// when `x` is known to be a multiple of the positive constant `d`.
+fn exact_division<I, C, P>(monomial: &Monomial<I, C, P>,
+                           assumptions: &Assumptions<I, C>)
+                           -> Polynomial<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    for (i, (c, p)) in monomial.powers.iter().enumerate() {
+        let (x, d) = match *c {
+            Composite::Floor(ref x, ref d) |
+            Composite::Ceil(ref x, ref d) if *p == P::one() && d.is_constant() => (x, d),
+            _ => continue,
+        };
+        let d = match d.monomials.first() {
+            Some(m) if m.coefficient > C::zero() => m.coefficient.clone(),
+            _ => continue,
+        };
+        if monomial.coefficient.floor_mod(&d) == C::zero() &&
+           x.monomials.iter().all(|m| known_divisor(m, assumptions).floor_mod(&d) == C::zero()) {
+            let mut rest = monomial.clone();
+            rest.coefficient /= d;
+            rest.powers.remove(i);
+            let mut result = Polynomial::<I, C, P> { monomials: Vec::new() };
+            for m in &(&**x * &rest).monomials {
+                result += &exact_division(m, assumptions);
+            }
+            return result;
+        }
+    }
+    Polynomial::from(monomial)
+}
+
+/// Substitutes each variable present in `values` with the polynomial it is mapped to.
+pub fn substitute<I, C, P, T>(polynomial: T,
+                              values: &HashMap<I, Polynomial<I, C, P>>)
+                              -> Polynomial<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power,
+          T: AsRef<Polynomial<I, C, P>> {
+    substitute_with(polynomial, values, &Assumptions::new())
+}
+
+/// Substitutes each variable present in `values` with the polynomial it is mapped to,
+/// simplifying the resulting composites under the given assumptions.
+fn substitute_with<I, C, P, T>(polynomial: T,
+                               values: &HashMap<I, Polynomial<I, C, P>>,
+                               assumptions: &Assumptions<I, C>)
+                               -> Polynomial<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power,
+          T: AsRef<Polynomial<I, C, P>> {
+    let polynomial = polynomial.as_ref();
+    let mut result = Polynomial::<I, C, P> { monomials: Vec::new() };
+    for m in &polynomial.monomials {
+        let mut product = Polynomial::from(m.coefficient.clone());
+        // The divisions are done last, so that they are exact whenever possible
+        let (negative, positive): (Vec<_>, Vec<_>) =
+            m.powers.iter().partition(|&(_, p)| *p < P::zero());
+        for (c, p) in positive.into_iter().chain(negative) {
+            let value = substitute_composite(c, values, assumptions);
+            multiply_power(&mut product, c, &value, p);
+        }
+        result += &product;
+    }
+    result
+}
+
+/// Multiplies the `product` by `factor`, the value of the `composite`, raised to the power `p`,
+/// dividing by it instead if the power is negative. If such a division is not exact, the
+/// product is divided by the unsubstituted `composite` instead.
+pub(crate) fn multiply_power<I, C, P>(product: &mut Polynomial<I, C, P>,
+                           composite: &Composite<I, C, P>,
+                           factor: &Polynomial<I, C, P>,
+                           p: &P)
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    if *p < P::zero() {
+        for _ in 0..(P::zero() - p.clone()).to_usize().unwrap() {
+            let laurent = || match factor.monomials[..] {
+                [ref m] => product.laurent_div(m),
+                _ => None,
+            };
+            *product = match product.checked_div(factor).or_else(laurent) {
+                Some(result) => result,
+                None => {
+                    &*product *
+                    &Monomial {
+                        coefficient: C::one(),
+                        powers: vec![(composite.clone(), P::zero() - P::one())],
+                    }
+                }
+            };
+        }
+    } else {
+        for _ in 0..p.to_usize().unwrap() {
+            *product *= factor;
+        }
+    }
+}
+
+fn substitute_composite<I, C, P>(composite: &Composite<I, C, P>,
+                                 values: &HashMap<I, Polynomial<I, C, P>>,
+                                 assumptions: &Assumptions<I, C>)
+                                 -> Polynomial<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    let substitute = |x: &Polynomial<I, C, P>| substitute_with(x, values, assumptions);
+    match *composite {
+        Composite::Variable(ref id) => {
+            match values.get(id) {
+                Some(value) => value.clone(),
+                None => variable(id.clone()),
+            }
+        }
+        Composite::Floor(ref left, ref right) => {
+            floor_with(substitute(left), substitute(right), assumptions)
+        }
+        Composite::Ceil(ref left, ref right) => {
+            ceil_with(substitute(left), substitute(right), assumptions)
+        }
+        Composite::Min(ref args) |
+        Composite::Max(ref args) |
+        Composite::Gcd(ref args) |
+        Composite::Lcm(ref args) => {
+            let mut args = args.iter().map(|arg| substitute(arg));
+            let first = args.next().unwrap();
+            args.fold(first, |result, arg| match *composite {
+                Composite::Min(_) => min_with(result, arg, assumptions),
+                Composite::Max(_) => max_with(result, arg, assumptions),
+                Composite::Gcd(_) => gcd(result, arg),
+                _ => lcm(result, arg),
+            })
+        }
+        Composite::Mod(ref left, ref right) => modulo(substitute(left), substitute(right)),
+        Composite::TruncDiv(ref left, ref right) => trunc_div(substitute(left), substitute(right)),
+        Composite::RoundDiv(ref left, ref right) => round_div(substitute(left), substitute(right)),
+        Composite::Abs(ref x) => abs(substitute(x)),
+        Composite::Sign(ref x) => sign(substitute(x)),
+        Composite::Exp2(ref x) => exp2(substitute(x)),
+        Composite::Log2Floor(ref x) => log2_floor(substitute(x)),
+        Composite::Log2Ceil(ref x) => log2_ceil(substitute(x)),
+        Composite::Select(ref cond, ref then, ref otherwise) => {
+            let cond = Condition::new(
+                substitute(&cond.left),
+                cond.relation,
+                substitute(&cond.right),
+            );
+            select(cond, substitute(then), substitute(otherwise))
+        }
+        Composite::Apply(ref id, ref args) => {
+            let args: Vec<_> = args.iter().map(|arg| substitute(arg)).collect();
+            apply(id.clone(), &args)
+        }
+    }
+}
+
+/// Computes the discrete forward difference `p(x + 1) - p(x)` of the polynomial
+/// with respect to the variable `var`.
+pub fn forward_difference<I, C, P, T>(polynomial: T, var: &I) -> Polynomial<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power,
+          T: AsRef<Polynomial<I, C, P>> {
+    let polynomial = polynomial.as_ref();
+    let mut values = HashMap::new();
+    values.insert(var.clone(), variable::<I, C, P>(var.clone()) + C::one());
+    substitute(polynomial, &values) - polynomial
+}
+
+/// Computes in closed form the sum `p(lo) + p(lo + 1) + ... + p(hi)` of the polynomial
+/// over the variable `var`, where the bounds `lo` and `hi` can themselves be polynomials.
+///
+/// The sums of powers are expanded using Faulhaber's formulas. As these have rational
+/// coefficients, the result is expressed as an exact `floor` of an integer polynomial
+/// by the common denominator, whenever that denominator does not cancel out, e.g.
+/// `sum_{i=1}^{n} i^2 = floor(2n^3 + 3n^2 + n, 6)`. The value of that `floor` is exact,
+/// but it is an opaque composite, hence the result can not be expanded or combined with
+/// other polynomials beyond that (e.g. `2 * floor(n^2 + n, 2)` stays as it is, rather than
+/// becoming `n^2 + n`). Only when the denominator cancels out is a plain polynomial returned.
+/// Fails if `var` appears inside of a composite expression (e.g. `floor`).
+pub fn sum_over<I, C, P, T1, T2, T3>(polynomial: T1,
+                                     var: &I,
+                                     lo: T2,
+                                     hi: T3)
+                                     -> Result<Polynomial<I, C, P>, (I, String)>
+    where I: Id,
+          C: IntegerCoefficient,
+          P: Power,
+          T1: AsRef<Polynomial<I, C, P>>,
+          T2: AsRef<Polynomial<I, C, P>>,
+          T3: AsRef<Polynomial<I, C, P>> {
+    let coefficients = polynomial.as_ref().coefficients(var)?;
+    let lo_minus_one = lo.as_ref() - C::one();
+    let hi = hi.as_ref();
+    let sums = faulhaber::<C>(coefficients.len());
+    let denominator = sums.iter().fold(C::one(), |acc, (_, d)| acc.lcm(d));
+    let mut numerator = Polynomial::<I, C, P> { monomials: Vec::new() };
+    for (coefficient, (sum, d)) in coefficients.iter().zip(sums.iter()) {
+        if !coefficient.monomials.is_empty() {
+            let difference = horner(sum, hi) - horner(sum, &lo_minus_one);
+            numerator += &(coefficient * &difference * (denominator.clone() / d.clone()));
+        }
+    }
+    let content = numerator.monomials.iter().fold(denominator.clone(), |acc, m| {
+        acc.gcd(&m.coefficient)
+    });
+    numerator /= content.clone();
+    Ok(floor(numerator, Polynomial::from(denominator / content)))
+}
+
+/// For each `k < n` computes the integer coefficients `N_k` and the denominator `D_k`,
+/// such that `1^k + 2^k + ... + x^k = N_k(x) / D_k`.
+fn faulhaber<C>(n: usize) -> Vec<(Vec<C>, C)>
+    where C: IntegerCoefficient {
+    let from_usize = |x: usize| Ratio::from_integer(C::from_usize(x).unwrap());
+    let mut sums: Vec<Vec<Ratio<C>>> = Vec::new();
+    for k in 0..n {
+        // (k + 1) S_k(x) = (x + 1)^(k + 1) - 1 - sum_{j < k} binomial(k + 1, j) S_j(x)
+        let mut binomials = vec![from_usize(1)];
+        for j in 0..(k + 1) {
+            let next = binomials[j].clone() * from_usize(k + 1 - j) / from_usize(j + 1);
+            binomials.push(next);
+        }
+        let mut sum = binomials.clone();
+        sum[0] = sum[0].clone() - from_usize(1);
+        for (j, s_j) in sums.iter().enumerate() {
+            for (i, value) in s_j.iter().enumerate() {
+                sum[i] = sum[i].clone() - binomials[j].clone() * value.clone();
+            }
+        }
+        for value in &mut sum {
+            *value = value.clone() / from_usize(k + 1);
+        }
+        sums.push(sum);
+    }
+    sums.into_iter()
+        .map(|sum| {
+            let denominator = sum.iter().fold(C::one(), |acc, v| acc.lcm(v.denom()));
+            let integers = sum.iter()
+                .map(|v| v.numer().clone() * (denominator.clone() / v.denom().clone()))
+                .collect();
+            (integers, denominator)
+        })
+        .collect()
+}
+
+/// Evaluates the univariate polynomial with the given integer coefficients at `x`.
+fn horner<I, C, P>(coefficients: &[C], x: &Polynomial<I, C, P>) -> Polynomial<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    let mut result = Polynomial { monomials: Vec::new() };
+    for c in coefficients.iter().rev() {
+        result *= x;
+        result += c.clone();
     }
     result
 }
@@ -297,7 +1493,7 @@ pub fn reduce<I, C, P, T>(polynomial: T, values: &HashMap<I, C>) -> Polynomial<I
 /// system of equations specified by the mapping of `Polynomial` to a constant value.
 pub fn deduce_values<I, C, P, T>(original_values: &[(T, C)]) -> Result<HashMap<I, C>, String>
     where I: Id,
-          C: Coefficient,
+          C: IntegerCoefficient,
           P: Power,
           T: AsRef<Polynomial<I, C, P>> {
     //    let mut implicit_values = vec![(Polynomial::default(), C::zero()); original_values.len()];
@@ -388,7 +1584,7 @@ pub fn deduce_values<I, C, P, T>(original_values: &[(T, C)]) -> Result<HashMap<I
 }
 
 fn nth_root<C, P>(value: &C, n: P) -> Option<C>
-    where C: Coefficient,
+    where C: IntegerCoefficient,
           P: Power {
     let result = if value < &C::zero() {
         C::from_f64(-(-value.to_f64().unwrap()).powf(n.to_f64().unwrap().recip())).unwrap()
@@ -1,9 +1,12 @@
 use std::ops::{Add, Sub, Mul, Div};
 use std::cmp::Ordering;
 
+use num::bigint::BigInt;
+
 use traits::*;
 use monomial::Monomial;
 use polynomial::Polynomial;
+use rational_function::RationalFunction;
 
 macro_rules! impl_all {
     ( $( $type_:ty ),* )  => {$(
@@ -46,17 +49,7 @@ macro_rules! impl_all {
             type Output = Option<Monomial<I, $type_, P>>;
             fn div(self, rhs: &'a Monomial<I, $type_, P>) -> Self::Output {
                 if rhs.is_constant() {
-                    let c = self;
-                    match c.checked_div(rhs.coefficient) {
-                        Some(v) => {
-                            if v * c.clone() == rhs.coefficient {
-                                Some(Monomial::from(c / rhs.coefficient.clone()))
-                            } else {
-                                None
-                            }
-                        },
-                        _ => None
-                    }
+                    self.exact_div(&rhs.coefficient).map(Monomial::from)
                 } else {
                     None
                 }
@@ -178,7 +171,63 @@ macro_rules! impl_all {
                 -&((&rhs).add(-self))
             }
         }
+        impl<'a, I, P> Add<&'a RationalFunction<I, $type_, P>> for $type_
+            where I: Id, P: Power {
+            type Output = RationalFunction<I, $type_, P>;
+            fn add(self, rhs: &'a RationalFunction<I, $type_, P>) -> Self::Output {
+                RationalFunction::from(self).add(rhs)
+            }
+        }
+        impl<I, P> Add<RationalFunction<I, $type_, P>> for $type_
+            where I: Id, P: Power {
+            type Output = RationalFunction<I, $type_, P>;
+            fn add(self, rhs: RationalFunction<I, $type_, P>) -> Self::Output {
+                RationalFunction::from(self).add(&rhs)
+            }
+        }
+        impl<'a, I, P> Sub<&'a RationalFunction<I, $type_, P>> for $type_
+            where I: Id, P: Power {
+            type Output = RationalFunction<I, $type_, P>;
+            fn sub(self, rhs: &'a RationalFunction<I, $type_, P>) -> Self::Output {
+                RationalFunction::from(self).sub(rhs)
+            }
+        }
+        impl<I, P> Sub<RationalFunction<I, $type_, P>> for $type_
+            where I: Id, P: Power {
+            type Output = RationalFunction<I, $type_, P>;
+            fn sub(self, rhs: RationalFunction<I, $type_, P>) -> Self::Output {
+                RationalFunction::from(self).sub(&rhs)
+            }
+        }
+        impl<'a, I, P> Mul<&'a RationalFunction<I, $type_, P>> for $type_
+            where I: Id, P: Power {
+            type Output = RationalFunction<I, $type_, P>;
+            fn mul(self, rhs: &'a RationalFunction<I, $type_, P>) -> Self::Output {
+                RationalFunction::from(self).mul(rhs)
+            }
+        }
+        impl<I, P> Mul<RationalFunction<I, $type_, P>> for $type_
+            where I: Id, P: Power {
+            type Output = RationalFunction<I, $type_, P>;
+            fn mul(self, rhs: RationalFunction<I, $type_, P>) -> Self::Output {
+                RationalFunction::from(self).mul(&rhs)
+            }
+        }
+        impl<'a, I, P> Div<&'a RationalFunction<I, $type_, P>> for $type_
+            where I: Id, P: Power {
+            type Output = RationalFunction<I, $type_, P>;
+            fn div(self, rhs: &'a RationalFunction<I, $type_, P>) -> Self::Output {
+                RationalFunction::from(self).div(rhs)
+            }
+        }
+        impl<I, P> Div<RationalFunction<I, $type_, P>> for $type_
+            where I: Id, P: Power {
+            type Output = RationalFunction<I, $type_, P>;
+            fn div(self, rhs: RationalFunction<I, $type_, P>) -> Self::Output {
+                RationalFunction::from(self).div(&rhs)
+            }
+        }
     )*};
 }
 
-impl_all!(i64, i32, i16, i8, isize);
+impl_all!(i128, i64, i32, i16, i8, isize, BigInt);
@@ -10,12 +10,20 @@
 //! which is used to define the ordering of the polynomials as well -
 //! [Wikipedia](https://en.wikipedia.org/wiki/Gr%C3%B6bner_basis#Monomial_ordering).
 //!
-//! `C: Coefficient` - the variable `Integer` type of the internal coefficients for each monomial.
+//! `C: Coefficient` - the type of the internal coefficients for each monomial. This is either an
+//! integer type or a field such as `Ratio<i64>`, in which case division is always exact and
+//! `floor`/`ceil` round the rational value. Variables are always assumed to take integer values.
+//! All signed primitive integers as well as `BigInt` can be used, and for those the scalar can
+//! also appear on the left of an operator, e.g. `3 * p` or `5 - p`.
+//! For fast identity testing the prime field `ModInt<P>` can be used, converting an integer
+//! polynomial without composite expressions into it with `map_coefficients`.
 //! The `Polynomial<C, I, P>` have implemented standard operators for interacting with type `C`.
 //! Whenever you evaluate a polynomial, the output would be of this type.
 //!
 //! `P: Power` - the `Integer` type of the power values of each monomial, e.g. if we have a^x ,
-//! then `x: Power`. Note that it is required that `P` is an `Unsigned` type.
+//! then `x: Power`. With an `Unsigned` type the powers are never negative, while a signed type
+//! opts into Laurent polynomials, where dividing by a monomial such as `n / a` gives `n a^-1`.
+//! Evaluating those fails if the result is not an integer.
 //!
 //! The choice of `C` and `P` should depend on the problem you are using the library for. If you
 //! do not expect too high power values setting `P` to `u8` or `u16` should suffice.
@@ -118,6 +126,8 @@
 #[cfg(feature = "serialize")]
 #[macro_use]
 extern crate serde_derive;
+#[cfg(feature = "serialize")]
+extern crate serde;
 
 extern crate num;
 
@@ -125,12 +135,26 @@ mod traits;
 mod functions;
 mod monomial;
 mod polynomial;
+mod rational_function;
 mod composite;
+mod condition;
+mod registry;
+mod assumptions;
+mod proof;
+mod modular;
 mod integer_impl;
+mod elimination;
 
 pub use traits::*;
 pub use monomial::*;
 pub use polynomial::*;
+pub use rational_function::*;
 pub use composite::*;
+pub use condition::*;
+pub use registry::*;
+pub use assumptions::*;
+pub use proof::*;
+pub use modular::*;
 pub use functions::*;
 pub use integer_impl::*;
+pub use elimination::*;
@@ -0,0 +1,296 @@
+use std::ops::{Add, Sub, Mul, Div, Rem, Neg, AddAssign, SubAssign, MulAssign, DivAssign};
+use num::{Num, One, Zero, FromPrimitive};
+
+use traits::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "repr_c", repr(C))]
+/// An element of the prime field `Z/PZ`, stored as its representative in `[0, P)`.
+///
+/// This can be used as a `Coefficient` for fast identity testing and hashing of large
+/// polynomials, e.g. by converting them with `map_coefficients`. As every non-zero element is
+/// invertible, division is always exact and `floor_div` is the same as the field division,
+/// leaving a zero remainder. The ordering is the one of the representatives, hence any reasoning
+/// about signs (e.g. `abs` or proving inequalities) is not meaningful. The modulus `P` must be
+/// a prime, which is checked at compile time whenever an element is created:
+///
+/// ```compile_fail
+/// let x = symbolic_polynomials::ModInt::<15>::new(2);
+/// ```
+pub struct ModInt<const P: u64>(u64);
+
+impl<const P: u64> ModInt<P> {
+    /// Fails the compilation of any use of `ModInt<P>` with a modulus which is not a prime.
+    const PRIME: () = assert!(is_prime(P), "The modulus of a ModInt must be a prime.");
+
+    /// Creates the element corresponding to `value`.
+    pub fn new(value: u64) -> Self {
+        let () = Self::PRIME;
+        ModInt(value % P)
+    }
+
+    /// Returns the representative of the element in `[0, P)`.
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+
+    /// Raises the element to the power `exponent` by repeated squaring.
+    pub fn pow(&self, exponent: u64) -> Self {
+        let mut result = Self::one();
+        let mut base = *self;
+        let mut exponent = exponent;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exponent >>= 1;
+        }
+        result
+    }
+
+    /// Returns the multiplicative inverse of the element, or None if it is zero.
+    pub fn inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            // By Fermat's little theorem a^(P - 1) = 1
+            Some(self.pow(P - 2))
+        }
+    }
+}
+
+/// Computes `a * b mod n` without overflowing.
+const fn mul_mod(a: u64, b: u64, n: u64) -> u64 {
+    ((a as u128 * b as u128) % n as u128) as u64
+}
+
+/// Computes `base^exponent mod n` by repeated squaring.
+const fn pow_mod(base: u64, exponent: u64, n: u64) -> u64 {
+    let mut result = 1 % n;
+    let mut base = base % n;
+    let mut exponent = exponent;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, n);
+        }
+        base = mul_mod(base, base, n);
+        exponent >>= 1;
+    }
+    result
+}
+
+/// Checks whether `n` is a prime with the Miller-Rabin test, which is deterministic
+/// for all 64-bit values when using the first twelve primes as the bases.
+// `u64::is_multiple_of` would raise the minimum supported Rust version to 1.87
+#[allow(clippy::manual_is_multiple_of)]
+const fn is_prime(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 {
+        return false;
+    }
+    let mut i = 0;
+    while i < BASES.len() {
+        if n % BASES[i] == 0 {
+            return n == BASES[i];
+        }
+        i += 1;
+    }
+    // n - 1 = d * 2^s with an odd d
+    let mut d = n - 1;
+    let mut s = 0;
+    while d % 2 == 0 {
+        d /= 2;
+        s += 1;
+    }
+    let mut i = 0;
+    'bases: while i < BASES.len() {
+        let mut x = pow_mod(BASES[i], d, n);
+        i += 1;
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        let mut r = 1;
+        while r < s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                continue 'bases;
+            }
+            r += 1;
+        }
+        return false;
+    }
+    true
+}
+
+// The serde derive can not handle const generics, hence it is serialized as the representative
+#[cfg(feature = "serialize")]
+impl<const P: u64> ::serde::Serialize for ModInt<P> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: ::serde::Serializer {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<const P: u64> ::serde::Deserialize for ModInt<P> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: ::serde::Deserializer {
+        u64::deserialize(deserializer).map(ModInt::new)
+    }
+}
+
+impl<const P: u64> Coefficient for ModInt<P> {
+    fn exact_div(&self, other: &Self) -> Option<Self> {
+        other.inverse().map(|inverse| *self * inverse)
+    }
+
+    fn floor_div(&self, other: &Self) -> Self {
+        *self / *other
+    }
+
+    fn greatest_common_divisor(&self, other: &Self) -> Self {
+        if self.is_zero() && other.is_zero() {
+            Self::zero()
+        } else {
+            Self::one()
+        }
+    }
+
+    fn least_common_multiple(&self, other: &Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            Self::zero()
+        } else {
+            Self::one()
+        }
+    }
+
+    fn as_usize(&self) -> Option<usize> {
+        ::num::ToPrimitive::to_usize(&self.0)
+    }
+
+    fn as_f64(&self) -> f64 {
+        self.0 as f64
+    }
+}
+
+impl<const P: u64> Default for ModInt<P> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<const P: u64> Zero for ModInt<P> {
+    fn zero() -> Self {
+        let () = Self::PRIME;
+        ModInt(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl<const P: u64> One for ModInt<P> {
+    fn one() -> Self {
+        ModInt::new(1)
+    }
+}
+
+impl<const P: u64> Num for ModInt<P> {
+    type FromStrRadixErr = ::std::num::ParseIntError;
+
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        u64::from_str_radix(str, radix).map(ModInt::new)
+    }
+}
+
+impl<const P: u64> FromPrimitive for ModInt<P> {
+    fn from_i64(n: i64) -> Option<Self> {
+        let () = Self::PRIME;
+        Some(ModInt(i128::from(n).rem_euclid(i128::from(P)) as u64))
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(ModInt::new(n))
+    }
+}
+
+impl<const P: u64> Add for ModInt<P> {
+    type Output = ModInt<P>;
+    fn add(self, rhs: ModInt<P>) -> Self::Output {
+        ModInt(((u128::from(self.0) + u128::from(rhs.0)) % u128::from(P)) as u64)
+    }
+}
+
+impl<const P: u64> Sub for ModInt<P> {
+    type Output = ModInt<P>;
+    fn sub(self, rhs: ModInt<P>) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl<const P: u64> Mul for ModInt<P> {
+    type Output = ModInt<P>;
+    fn mul(self, rhs: ModInt<P>) -> Self::Output {
+        ModInt(((u128::from(self.0) * u128::from(rhs.0)) % u128::from(P)) as u64)
+    }
+}
+
+impl<const P: u64> Div for ModInt<P> {
+    type Output = ModInt<P>;
+    fn div(self, rhs: ModInt<P>) -> Self::Output {
+        match self.exact_div(&rhs) {
+            Some(quotient) => quotient,
+            None => panic!("Attempting division by zero."),
+        }
+    }
+}
+
+impl<const P: u64> Rem for ModInt<P> {
+    type Output = ModInt<P>;
+    fn rem(self, rhs: ModInt<P>) -> Self::Output {
+        if rhs.is_zero() {
+            panic!("Attempting division by zero.")
+        }
+        // Every division is exact, so the remainder is always zero
+        ModInt::zero()
+    }
+}
+
+impl<const P: u64> Neg for ModInt<P> {
+    type Output = ModInt<P>;
+    fn neg(self) -> Self::Output {
+        ModInt((P - self.0) % P)
+    }
+}
+
+impl<const P: u64> AddAssign for ModInt<P> {
+    fn add_assign(&mut self, rhs: ModInt<P>) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const P: u64> SubAssign for ModInt<P> {
+    fn sub_assign(&mut self, rhs: ModInt<P>) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const P: u64> MulAssign for ModInt<P> {
+    fn mul_assign(&mut self, rhs: ModInt<P>) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const P: u64> DivAssign for ModInt<P> {
+    fn div_assign(&mut self, rhs: ModInt<P>) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const P: u64> ::std::fmt::Display for ModInt<P> {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
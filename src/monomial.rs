@@ -1,7 +1,8 @@
 use std::ops::{MulAssign, DivAssign, Add, Neg, Sub, Mul, Div};
 use std::collections::{HashMap, HashSet};
-use std::cmp::{Ord, Ordering};
+use std::cmp::{Ord, Ordering, max};
 use std::convert::AsRef;
+use std::rc::Rc;
 
 use traits::*;
 use polynomial::Polynomial;
@@ -55,13 +56,80 @@ impl<I, C, P> Monomial<I, C, P>
         self.powers.is_empty()
     }
 
+    /// Returns `true` if any of the powers is negative, which is possible only for a signed `P`.
+    pub fn has_negative_powers(&self) -> bool {
+        self.powers.iter().any(|(_, pow)| *pow < P::zero())
+    }
+
     /// Evaluates the `Monomial` given the provided mapping of identifiers to value assignments.
+    /// Fails if a negative power makes the result non-integral.
     pub fn eval(&self, values: &HashMap<I, C>) -> Result<C, (I, String)> {
-        let mut value = self.coefficient.clone();
+        let (numerator, denominator) = self.eval_fraction(values)?;
+        if let Some(value) = numerator.exact_div(&denominator) {
+            Ok(value)
+        } else {
+            Err((I::default(), format!("The value of {} is not an integer.", self)))
+        }
+    }
+
+    /// Evaluates the `Monomial` in floating point, as in `Polynomial::eval_f64`.
+    pub fn eval_f64(&self, values: &HashMap<I, f64>) -> f64 {
+        self.powers.iter().fold(self.coefficient.as_f64(), |acc, (c, pow)| {
+            acc * c.eval_f64(values).powf(pow.to_f64().unwrap())
+        })
+    }
+
+    /// Evaluates the `Monomial` as the fraction `(numerator, denominator)`, where the
+    /// denominator is the product of all the factors with negative powers.
+    pub fn eval_fraction(&self, values: &HashMap<I, C>) -> Result<(C, C), (I, String)> {
+        let mut numerator = self.coefficient.clone();
+        let mut denominator = C::one();
         for &(ref c, ref pow) in &self.powers {
-            value *= ::num::pow(c.eval(values)?, pow.to_usize().unwrap());
+            if *pow < P::zero() {
+                let pow = (P::zero() - pow.clone()).to_usize().unwrap();
+                denominator *= ::num::pow(c.eval(values)?, pow);
+            } else {
+                numerator *= ::num::pow(c.eval(values)?, pow.to_usize().unwrap());
+            }
+        }
+        if denominator == C::zero() {
+            Err((I::default(), "Attempting division by zero.".into()))
+        } else {
+            Ok((numerator, denominator))
+        }
+    }
+
+    /// Computes sound lower and upper bounds of the `Monomial`, given the provided mapping
+    /// of identifiers to the inclusive intervals of their values.
+    pub fn eval_interval(&self, values: &HashMap<I, (C, C)>) -> Result<(C, C), (I, String)> {
+        let mut bounds = (self.coefficient.clone(), self.coefficient.clone());
+        for (c, pow) in &self.powers {
+            if *pow < P::zero() {
+                return Err((I::default(), format!("Cannot bound the negative power of {}.", c)));
+            }
+            let (low, high) = c.eval_interval(values)?;
+            let pow = pow.to_usize().unwrap();
+            let (v_low, v_high) = (::num::pow(low.clone(), pow), ::num::pow(high.clone(), pow));
+            // An even power is non-decreasing only for non-negative values
+            let factor = if pow % 2 == 1 || low >= C::zero() {
+                (v_low, v_high)
+            } else if high <= C::zero() {
+                (v_high, v_low)
+            } else {
+                (C::zero(), max(v_low, v_high))
+            };
+            let products = [
+                bounds.0.clone() * factor.0.clone(),
+                bounds.0.clone() * factor.1.clone(),
+                bounds.1.clone() * factor.0.clone(),
+                bounds.1.clone() * factor.1.clone(),
+            ];
+            bounds = (
+                products.iter().min().unwrap().clone(),
+                products.iter().max().unwrap().clone(),
+            );
         }
-        Ok(value)
+        Ok(bounds)
     }
 
     /// Returns a code equivalent string representation of the `Monomial`.
@@ -79,12 +147,15 @@ impl<I, C, P> Monomial<I, C, P>
             str = "- ".into();
         } else if self.coefficient < C::zero() {
             str = "- ".into();
-            str.push_str(&(-self.coefficient.clone()).to_string());
+            str.push_str(&coefficient_to_string(&-self.coefficient.clone()));
         } else if self.coefficient != C::one() {
-            str = self.coefficient.clone().to_string();
+            str = coefficient_to_string(&self.coefficient);
         }
         let mut first = true;
         for &(ref c, ref pow) in &self.powers {
+            if *pow < P::zero() {
+                continue;
+            }
             if !first || (self.coefficient != C::one() && self.coefficient != -C::one()) {
                 str.push_str(" * ");
             }
@@ -99,6 +170,19 @@ impl<I, C, P> Monomial<I, C, P>
             }
             first = false;
         }
+        // Negative powers become divisions at the end, so that they are exact
+        if first && self.has_negative_powers() &&
+           (self.coefficient == C::one() || self.coefficient == -C::one()) {
+            str.push('1');
+        }
+        for (c, pow) in &self.powers {
+            if *pow < P::zero() {
+                for _ in 0..(P::zero() - pow.clone()).to_usize().unwrap() {
+                    str.push_str(" / ");
+                    str.push_str(&c.to_code(format));
+                }
+            }
+        }
         str
     }
 
@@ -108,6 +192,171 @@ impl<I, C, P> Monomial<I, C, P>
             c.unique_identifiers(unique);
         }
     }
+
+    /// Converts the coefficient of the `Monomial` with `f`, failing if it contains any composite
+    /// other than a variable, as in `Polynomial::map_coefficients`.
+    pub fn map_coefficients<C2, F>(&self, f: &F) -> Result<Monomial<I, C2, P>, (I, String)>
+        where C2: Coefficient,
+              F: Fn(&C) -> C2 {
+        let mut result = Monomial::from(f(&self.coefficient));
+        for (c, pow) in &self.powers {
+            result *= &Monomial {
+                coefficient: C2::one(),
+                powers: vec![(c.map_coefficients(f)?, pow.clone())],
+            };
+        }
+        Ok(result)
+    }
+
+    /// Converts the `Monomial` into one with different identifier, coefficient and power types,
+    /// as in `Polynomial::try_map`. As this can change the order of the composites they are
+    /// multiplied back together.
+    pub fn try_map<I2, C2, P2, FI, FC, FP>(&self,
+                                           id: &FI,
+                                           coefficient: &FC,
+                                           power: &FP)
+                                           -> Result<Monomial<I2, C2, P2>, (I, String)>
+        where I2: Id,
+              C2: Coefficient,
+              P2: Power,
+              FI: Fn(&I) -> I2,
+              FC: Fn(&C) -> Option<C2>,
+              FP: Fn(&P) -> Option<P2> {
+        let converted = coefficient(&self.coefficient).ok_or((
+            I::default(),
+            format!("Could not convert the coefficient {}.", self.coefficient),
+        ))?;
+        let mut result = Monomial::from(converted);
+        for (c, pow) in &self.powers {
+            let converted = power(pow).ok_or((
+                I::default(),
+                format!("Could not convert the power {} of {}.", pow, c),
+            ))?;
+            result *= &Monomial {
+                coefficient: C2::one(),
+                powers: vec![(c.try_map(id, coefficient, power)?, converted)],
+            };
+        }
+        Ok(result)
+    }
+
+    /// Multiplies the `Monomial` by `rhs`, returning whether any powers cancelled out or any
+    /// powers of two were merged, which can change its order relative to other monomials.
+    pub(crate) fn multiply(&mut self, rhs: &Monomial<I, C, P>) -> bool {
+        self.coefficient *= rhs.coefficient.clone();
+        let mut i1 = 0;
+        let mut i2 = 0;
+        while i1 < self.powers.len() && i2 < rhs.powers.len() {
+            match Ord::cmp(&self.powers[i1].0, &rhs.powers[i2].0) {
+                Ordering::Greater => {}
+                Ordering::Less => {
+                    self.powers.insert(i1, rhs.powers[i2].clone());
+                    i2 += 1;
+                }
+                Ordering::Equal => {
+                    self.powers[i1] = (
+                        self.powers[i1].0.clone(),
+                        self.powers[i1].1.clone() + rhs.powers[i2].1.clone(),
+                    );
+                    i2 += 1;
+                }
+            }
+            i1 += 1;
+        }
+        while i2 < rhs.powers.len() {
+            self.powers.push(rhs.powers[i2].clone());
+            i2 += 1;
+        }
+        // A negative power can cancel a positive one, as in `a * a^-1 = 1`
+        let count = self.powers.len();
+        self.powers.retain(|(_, pow)| *pow != P::zero());
+        let cancelled = self.powers.len() != count;
+        self.merge_powers_of_two() || cancelled
+    }
+
+    /// Merges all of the powers of two into a single one, as `2^a * 2^b = 2^(a + b)`,
+    /// returning whether there was anything to merge.
+    fn merge_powers_of_two(&mut self) -> bool {
+        let mut count = 0;
+        let mut exponent = Polynomial { monomials: Vec::new() };
+        for (c, p) in &self.powers {
+            if let Composite::Exp2(ref x) = *c {
+                count += 1;
+                exponent += &(&**x * C::from_i64(p.to_i64().unwrap()).unwrap());
+                if *p != P::one() {
+                    count += 1;
+                }
+            }
+        }
+        if count < 2 {
+            return false;
+        }
+        self.powers.retain(|(c, _)| !matches!(*c, Composite::Exp2(_)));
+        // A positive constant term of the exponent goes into the coefficient, as in `exp2`
+        let constant = match exponent.monomials.last() {
+            Some(m) if m.powers.is_empty() && m.coefficient > C::zero() => m.coefficient.as_usize(),
+            _ => None,
+        };
+        if let Some(k) = constant {
+            exponent.monomials.pop();
+            self.coefficient *= ::num::pow(C::one() + C::one(), k);
+        }
+        if !exponent.monomials.is_empty() {
+            self.multiply(&Monomial {
+                coefficient: C::one(),
+                powers: vec![(Composite::Exp2(Rc::new(exponent)), P::one())],
+            });
+        }
+        true
+    }
+
+    /// Returns the partial derivative of the `Monomial` with respect to the variable `var`.
+    /// Fails if `var` appears inside of a composite expression (e.g. `floor`),
+    /// as those are not differentiable.
+    pub fn derivative(&self, var: &I) -> Result<Polynomial<I, C, P>, (I, String)> {
+        let mut result = self.clone();
+        let mut index = None;
+        for (i, (c, pow)) in self.powers.iter().enumerate() {
+            match *c {
+                Composite::Variable(ref id) if id == var => {
+                    result.coefficient *= C::from_i64(pow.to_i64().unwrap()).unwrap();
+                    index = Some(i);
+                }
+                Composite::Variable(_) => {}
+                _ => {
+                    let mut unique = HashSet::new();
+                    c.unique_identifiers(&mut unique);
+                    if unique.contains(var) {
+                        return Err((
+                            var.clone(),
+                            format!("Can not differentiate {} with respect to {}.", c, var),
+                        ));
+                    }
+                }
+            }
+        }
+        match index {
+            Some(i) => {
+                if result.powers[i].1 == P::one() {
+                    result.powers.remove(i);
+                } else {
+                    result.powers[i].1 = result.powers[i].1.clone() - P::one();
+                }
+                Ok(Polynomial::from(result))
+            }
+            None => Ok(Polynomial { monomials: Vec::new() }),
+        }
+    }
+}
+
+/// Renders a non-negative coefficient, putting non-integral ones such as `1/2` into parentheses
+/// so that they are not confused with a division of the whole monomial.
+fn coefficient_to_string<C: Coefficient>(coefficient: &C) -> String {
+    if coefficient.floor_mod(&C::one()) == C::zero() {
+        coefficient.to_string()
+    } else {
+        format!("({})", coefficient)
+    }
 }
 
 impl<I, C, P> ::std::fmt::Display for Monomial<I, C, P>
@@ -124,9 +373,9 @@ impl<I, C, P> ::std::fmt::Display for Monomial<I, C, P>
         } else if self.coefficient == -C::one() {
             write!(f, "- ")?;
         } else if self.coefficient < C::zero() {
-            write!(f, "- {}", -self.coefficient.clone())?;
+            write!(f, "- {}", coefficient_to_string(&-self.coefficient.clone()))?;
         } else if self.coefficient != C::one() {
-            write!(f, "{}", self.coefficient.clone())?;
+            write!(f, "{}", coefficient_to_string(&self.coefficient))?;
         }
         for &(ref c, ref pow) in &self.powers {
             if pow == &P::one() {
@@ -282,30 +531,7 @@ impl<'a, I, C, P> MulAssign<&'a Monomial<I, C, P>> for Monomial<I, C, P>
           C: Coefficient,
           P: Power {
     fn mul_assign(&mut self, rhs: &'a Monomial<I, C, P>) {
-        self.coefficient *= rhs.coefficient.clone();
-        let mut i1 = 0;
-        let mut i2 = 0;
-        while i1 < self.powers.len() && i2 < rhs.powers.len() {
-            match Ord::cmp(&self.powers[i1].0, &rhs.powers[i2].0) {
-                Ordering::Greater => {}
-                Ordering::Less => {
-                    self.powers.insert(i1, rhs.powers[i2].clone());
-                    i2 += 1;
-                }
-                Ordering::Equal => {
-                    self.powers[i1] = (
-                        self.powers[i1].0.clone(),
-                        self.powers[i1].1.clone() + rhs.powers[i2].1.clone(),
-                    );
-                    i2 += 1;
-                }
-            }
-            i1 += 1;
-        }
-        while i2 < rhs.powers.len() {
-            self.powers.push(rhs.powers[i2].clone());
-            i2 += 1;
-        }
+        self.multiply(rhs);
     }
 }
 
@@ -445,8 +671,7 @@ impl<I, C, P> DivAssign<C> for Monomial<I, C, P>
           C: Coefficient,
           P: Power {
     fn div_assign(&mut self, rhs: C) {
-        let (d, rem) = self.coefficient.div_rem(&rhs);
-        if rem == C::zero() {
+        if let Some(d) = self.coefficient.exact_div(&rhs) {
             self.coefficient = d;
         } else {
             panic!("Non integer division via DivAssign")
@@ -462,10 +687,10 @@ impl<I, C, P> Monomial<I, C, P>
           C: Coefficient,
           P: Power {
     /// If the the monomial is divisible by `rhs` than returns the result
-    /// of that division, otherwise None.
+    /// of that division, otherwise None. This never introduces negative powers,
+    /// for which see `laurent_div`.
     pub fn checked_div(&self, rhs: &Monomial<I, C, P>) -> Option<Self> {
-        let (d, rem) = self.coefficient.div_rem(&rhs.coefficient);
-        if rem == C::zero() {
+        if let Some(d) = self.coefficient.exact_div(&rhs.coefficient) {
             let mut result = Monomial {
                 coefficient: d,
                 powers: self.powers.clone(),
@@ -506,6 +731,29 @@ impl<I, C, P> Monomial<I, C, P>
             None
         }
     }
+
+    /// Divides the monomial by `rhs`, allowing negative powers in the result when `P` is
+    /// a signed type, as in `n / a = n a^-1`. Returns None if the coefficients do not divide
+    /// or the result needs a negative power which `P` cannot represent.
+    pub fn laurent_div(&self, rhs: &Monomial<I, C, P>) -> Option<Self> {
+        if let Some(result) = self.checked_div(rhs) {
+            return Some(result);
+        }
+        let d = self.coefficient.exact_div(&rhs.coefficient)?;
+        let mut inverse = Monomial {
+            coefficient: C::one(),
+            powers: Vec::new(),
+        };
+        for (c, pow) in &rhs.powers {
+            inverse.powers.push((c.clone(), P::from_i64(-pow.to_i64()?)?));
+        }
+        let mut result = Monomial {
+            coefficient: d,
+            powers: self.powers.clone(),
+        };
+        result *= &inverse;
+        Some(result)
+    }
 }
 
 impl<'a, 'b, I, C, P> Div<&'b Monomial<I, C, P>> for &'a Monomial<I, C, P>
@@ -514,7 +762,7 @@ impl<'a, 'b, I, C, P> Div<&'b Monomial<I, C, P>> for &'a Monomial<I, C, P>
           P: Power {
     type Output = Monomial<I, C, P>;
     fn div(self, rhs: &'b Monomial<I, C, P>) -> Self::Output {
-        self.checked_div(rhs).unwrap()
+        self.laurent_div(rhs).unwrap()
     }
 }
 
@@ -524,7 +772,7 @@ impl<'b, I, C, P> Div<&'b Monomial<I, C, P>> for Monomial<I, C, P>
           P: Power {
     type Output = Monomial<I, C, P>;
     fn div(self, rhs: &'b Monomial<I, C, P>) -> Self::Output {
-        self.checked_div(rhs).unwrap()
+        self.laurent_div(rhs).unwrap()
     }
 }
 
@@ -534,7 +782,7 @@ impl<'a, I, C, P> Div<Monomial<I, C, P>> for &'a Monomial<I, C, P>
           P: Power {
     type Output = Monomial<I, C, P>;
     fn div(self, rhs: Monomial<I, C, P>) -> Self::Output {
-        self.checked_div(&rhs).unwrap()
+        self.laurent_div(&rhs).unwrap()
     }
 }
 impl<I, C, P> Div<Monomial<I, C, P>> for Monomial<I, C, P>
@@ -543,7 +791,7 @@ impl<I, C, P> Div<Monomial<I, C, P>> for Monomial<I, C, P>
           P: Power {
     type Output = Monomial<I, C, P>;
     fn div(self, rhs: Monomial<I, C, P>) -> Self::Output {
-        self.checked_div(&rhs).unwrap()
+        self.laurent_div(&rhs).unwrap()
     }
 }
 
@@ -552,7 +800,7 @@ impl<'a, I, C, P> DivAssign<&'a Monomial<I, C, P>> for Monomial<I, C, P>
           C: Coefficient,
           P: Power {
     fn div_assign(&mut self, rhs: &'a Monomial<I, C, P>) {
-        let result = (self as &Monomial<I, C, P>).checked_div(rhs).unwrap();
+        let result = (self as &Monomial<I, C, P>).laurent_div(rhs).unwrap();
         self.coefficient = result.coefficient;
         self.powers = result.powers.clone();
     }
@@ -1,10 +1,11 @@
 use std::ops::{AddAssign, SubAssign, MulAssign, DivAssign, Add, Neg, Sub, Mul, Div};
-use std::cmp::{Ord, Ordering};
+use std::cmp::{Ord, Ordering, max};
 use std::convert::AsRef;
 use std::collections::HashSet;
 
 use traits::*;
 use monomial::Monomial;
+use composite::Composite;
 
 #[derive(Clone, Default, Debug, Eq)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
@@ -41,18 +42,60 @@ impl<I, C, P> Polynomial<I, C, P>
     }
 
     /// Evaluates the `Polynomial` given the provided mapping of identifiers to value assignments.
+    /// Fails if a negative power makes the result non-integral.
     pub fn eval(&self, values: &::std::collections::HashMap<I, C>) -> Result<C, (I, String)> {
-        let mut value = C::zero();
+        // Negative powers are summed as fractions, as only the total has to be an integer
+        let mut numerator = C::zero();
+        let mut denominator = C::one();
         for m in &self.monomials {
-            value += m.eval(values)?;
+            let (m_numerator, m_denominator) = m.eval_fraction(values)?;
+            if m_denominator == C::one() {
+                numerator += m_numerator * denominator.clone();
+            } else {
+                let common = denominator.least_common_multiple(&m_denominator);
+                numerator = numerator * (common.clone() / denominator) +
+                            m_numerator * (common.clone() / m_denominator);
+                denominator = common;
+            }
+        }
+        if let Some(value) = numerator.exact_div(&denominator) {
+            Ok(value)
+        } else {
+            Err((I::default(), format!("The value of {} is not an integer.", self)))
         }
-        Ok(value)
+    }
+
+    /// Evaluates the `Polynomial` in floating point, e.g. for estimates which would overflow
+    /// the coefficient type. The integer functions are computed on the float values
+    /// (e.g. `floor` of the float quotient), while any variable without a value gives NaN.
+    pub fn eval_f64(&self, values: &::std::collections::HashMap<I, f64>) -> f64 {
+        self.monomials.iter().map(|m| m.eval_f64(values)).sum()
+    }
+
+    /// Computes sound lower and upper bounds of the `Polynomial`, given the provided mapping
+    /// of identifiers to the inclusive intervals of their values.
+    pub fn eval_interval(&self,
+                         values: &::std::collections::HashMap<I, (C, C)>)
+                         -> Result<(C, C), (I, String)> {
+        let mut bounds = (C::zero(), C::zero());
+        for m in &self.monomials {
+            let (low, high) = m.eval_interval(values)?;
+            bounds.0 += low;
+            bounds.1 += high;
+        }
+        Ok(bounds)
     }
 
     /// Returns a code equivalent string representation of the `Polynomial`.
     /// The `format` specifies a function how to render the identifiers.
     pub fn to_code<F>(&self, format: &F) -> String
         where F: ::std::ops::Fn(I) -> String {
+        if self.monomials.len() > 1 && self.monomials.iter().any(|m| m.has_negative_powers()) {
+            // Only the total is exact, hence all terms are put over a common denominator
+            let denominator = self.common_denominator();
+            let numerator = self * &denominator;
+            return format!("({}) / ({})", numerator.to_code(format), denominator.to_code(format));
+        }
         match self.monomials.len() {
             0 => "0".into(),
             _ => {
@@ -101,12 +144,367 @@ impl<I, C, P> Polynomial<I, C, P>
         }
     }
 
+    /// Divides the polynomial by the monomial `rhs`, allowing negative powers in the result
+    /// when `P` is a signed type, as in `n / a = n a^-1`. Returns None if any of the coefficients
+    /// is not divisible or the result needs a negative power which `P` cannot represent.
+    pub fn laurent_div(&self, rhs: &Monomial<I, C, P>) -> Option<Polynomial<I, C, P>> {
+        let mut result = Polynomial { monomials: Vec::new() };
+        for m in &self.monomials {
+            result += &m.laurent_div(rhs)?;
+        }
+        Some(result)
+    }
+
+    /// Returns the smallest monomial with a unit coefficient which clears all of the negative
+    /// powers of the `Polynomial` when multiplied with it, e.g. `a^2` for `n a^-2 + a^-1`.
+    pub fn common_denominator(&self) -> Monomial<I, C, P> {
+        let mut powers: Vec<(Composite<I, C, P>, P)> = Vec::new();
+        for m in &self.monomials {
+            for (c, pow) in m.powers.iter().filter(|&(_, pow)| *pow < P::zero()) {
+                let pow = P::zero() - pow.clone();
+                match powers.iter_mut().find(|(o_c, _)| o_c == c) {
+                    Some(entry) => entry.1 = max(entry.1.clone(), pow),
+                    None => powers.push((c.clone(), pow)),
+                }
+            }
+        }
+        let mut denominator = Monomial::from(C::one());
+        for (c, pow) in powers {
+            denominator *= &Monomial {
+                coefficient: C::one(),
+                powers: vec![(c, pow)],
+            };
+        }
+        denominator
+    }
+
     /// Fills into the `HashSet` all of the identifiers used in this `Polynomial`.
     pub fn unique_identifiers(&self, unique: &mut HashSet<I>) {
         for m in &self.monomials {
             m.unique_identifiers(unique);
         }
     }
+
+    /// Converts the `Polynomial` into one with different identifier, coefficient and power types,
+    /// including all of the polynomials inside of composite expressions. The monomials are
+    /// sorted again according to the new types, merging any which become equal and dropping
+    /// the ones whose coefficients become zero. Fails if `coefficient` or `power` can not
+    /// convert a value (e.g. on overflow when using `NumCast::from`).
+    pub fn try_map<I2, C2, P2, FI, FC, FP>(&self,
+                                           id: &FI,
+                                           coefficient: &FC,
+                                           power: &FP)
+                                           -> Result<Polynomial<I2, C2, P2>, (I, String)>
+        where I2: Id,
+              C2: Coefficient,
+              P2: Power,
+              FI: Fn(&I) -> I2,
+              FC: Fn(&C) -> Option<C2>,
+              FP: Fn(&P) -> Option<P2> {
+        let mut result = Polynomial { monomials: Vec::new() };
+        for m in &self.monomials {
+            result += &m.try_map(id, coefficient, power)?;
+        }
+        Ok(result)
+    }
+
+    /// Converts the identifiers of the `Polynomial` with `f`.
+    pub fn map_ids<I2, F>(&self, f: &F) -> Polynomial<I2, C, P>
+        where I2: Id,
+              F: Fn(&I) -> I2 {
+        self.try_map(f, &|c| Some(c.clone()), &|p| Some(p.clone())).unwrap()
+    }
+
+    /// Converts the coefficients of the `Polynomial` with `f`, e.g. into the prime
+    /// field `ModInt<P>`. As `f` is in general a ring homomorphism which does not commute with
+    /// composite expressions (e.g. `floor` or `max` of values reduced modulo a prime), this
+    /// fails if the `Polynomial` contains any composite other than a variable.
+    pub fn map_coefficients<C2, F>(&self, f: &F) -> Result<Polynomial<I, C2, P>, (I, String)>
+        where C2: Coefficient,
+              F: Fn(&C) -> C2 {
+        let mut result = Polynomial { monomials: Vec::new() };
+        for m in &self.monomials {
+            result += &m.map_coefficients(f)?;
+        }
+        Ok(result)
+    }
+
+    /// Converts the coefficients of the `Polynomial` with `f`, failing if any of them
+    /// can not be converted.
+    pub fn try_map_coefficients<C2, F>(&self, f: &F) -> Result<Polynomial<I, C2, P>, (I, String)>
+        where C2: Coefficient,
+              F: Fn(&C) -> Option<C2> {
+        self.try_map(&I::clone, f, &|p| Some(p.clone()))
+    }
+
+    /// Converts the powers of the `Polynomial` with `f`, failing if any of them
+    /// can not be converted.
+    pub fn try_map_powers<P2, F>(&self, f: &F) -> Result<Polynomial<I, C, P2>, (I, String)>
+        where P2: Power,
+              F: Fn(&P) -> Option<P2> {
+        self.try_map(&I::clone, &|c| Some(c.clone()), f)
+    }
+
+    /// Returns the coefficients of the `Polynomial` viewed as a univariate polynomial in `var`,
+    /// such that the `i`-th entry is the coefficient of `var^i`.
+    /// Fails if `var` appears inside of a composite expression (e.g. `floor`).
+    pub fn coefficients(&self, var: &I) -> Result<Vec<Self>, (I, String)> {
+        let mut result: Vec<Polynomial<I, C, P>> = Vec::new();
+        for m in &self.monomials {
+            let mut rest = m.clone();
+            let mut power = 0;
+            for (i, (c, pow)) in m.powers.iter().enumerate() {
+                match *c {
+                    Composite::Variable(ref id) if id == var => {
+                        power = match pow.to_usize() {
+                            Some(power) => power,
+                            None => {
+                                return Err((
+                                    var.clone(),
+                                    format!("The variable {} has a negative power in {}.", var, m),
+                                ))
+                            }
+                        };
+                        rest.powers.remove(i);
+                    }
+                    Composite::Variable(_) => {}
+                    _ => {
+                        let mut unique = HashSet::new();
+                        c.unique_identifiers(&mut unique);
+                        if unique.contains(var) {
+                            return Err((
+                                var.clone(),
+                                format!("The variable {} is part of the composite {}.", var, c),
+                            ));
+                        }
+                    }
+                }
+            }
+            while result.len() <= power {
+                result.push(Polynomial { monomials: Vec::new() });
+            }
+            result[power] += &rest;
+        }
+        Ok(result)
+    }
+
+    /// Returns the resultant of the two polynomials with respect to the variable `var`,
+    /// computed as the determinant of their Sylvester matrix. The result does not depend on
+    /// `var` and is zero only if the two polynomials have a common factor in `var`.
+    /// Fails if `var` appears inside of a composite expression (e.g. `floor`).
+    pub fn resultant(&self, other: &Polynomial<I, C, P>, var: &I) -> Result<Self, (I, String)> {
+        let p = self.coefficients(var)?;
+        let q = other.coefficients(var)?;
+        if p.is_empty() || q.is_empty() {
+            return Ok(Polynomial { monomials: Vec::new() });
+        }
+        let m = p.len() - 1;
+        let n = q.len() - 1;
+        let zero = Polynomial { monomials: Vec::new() };
+        let mut matrix = vec![vec![zero; m + n]; m + n];
+        for i in 0..n {
+            for (j, c) in p.iter().rev().enumerate() {
+                matrix[i][i + j] = c.clone();
+            }
+        }
+        for i in 0..m {
+            for (j, c) in q.iter().rev().enumerate() {
+                matrix[n + i][i + j] = c.clone();
+            }
+        }
+        Ok(determinant(matrix))
+    }
+
+    /// Returns the discriminant of the polynomial with respect to the variable `var`,
+    /// which is zero only if the polynomial has a repeated root in `var`.
+    /// Fails if `var` appears inside of a composite expression (e.g. `floor`)
+    /// or if the polynomial does not depend on `var`.
+    pub fn discriminant(&self, var: &I) -> Result<Self, (I, String)> {
+        let p = self.coefficients(var)?;
+        if p.len() < 2 {
+            return Err((
+                var.clone(),
+                format!("The polynomial {} does not depend on {}.", self, var),
+            ));
+        }
+        let m = p.len() - 1;
+        let resultant = self.resultant(&self.derivative(var)?, var)?;
+        let result = resultant.checked_div(&p[m]).unwrap();
+        if (m * (m - 1) / 2) % 2 == 0 {
+            Ok(result)
+        } else {
+            Ok(-result)
+        }
+    }
+
+    /// Returns the partial derivative of the `Polynomial` with respect to the variable `var`.
+    /// Fails if `var` appears inside of a composite expression (e.g. `floor`),
+    /// as those are not differentiable.
+    pub fn derivative(&self, var: &I) -> Result<Polynomial<I, C, P>, (I, String)> {
+        let mut result = Polynomial { monomials: Vec::new() };
+        for m in &self.monomials {
+            result += &m.derivative(var)?;
+        }
+        Ok(result)
+    }
+
+    /// Returns the greatest common divisor of the two polynomials with a positive leading
+    /// coefficient, e.g. `a + b` for `a^2 - b^2` and `a^2 + 2ab + b^2`. Composite expressions
+    /// (e.g. `floor`) are treated as independent variables, while with negative powers only
+    /// the common constant factor is found.
+    pub fn greatest_common_divisor(&self, other: &Polynomial<I, C, P>) -> Self {
+        let result = common_divisor(self, other);
+        match result.monomials.first() {
+            Some(m) if m.coefficient < C::zero() => -result,
+            _ => result,
+        }
+    }
+}
+
+/// Computes the greatest common divisor of the two polynomials up to its sign, by viewing them
+/// as univariate polynomials in one of their composites with a primitive remainder sequence.
+fn common_divisor<I, C, P>(p: &Polynomial<I, C, P>, q: &Polynomial<I, C, P>) -> Polynomial<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    if p.monomials.is_empty() {
+        return q.clone();
+    } else if q.monomials.is_empty() {
+        return p.clone();
+    }
+    let monomials = || p.monomials.iter().chain(q.monomials.iter());
+    let atom = match monomials().flat_map(|m| m.powers.iter()).next() {
+        Some((c, _)) if !monomials().any(|m| m.has_negative_powers()) => c.clone(),
+        _ => {
+            let mut result = C::zero();
+            for m in monomials() {
+                result = result.greatest_common_divisor(&m.coefficient);
+            }
+            return Polynomial::from(result);
+        }
+    };
+    let mut p = univariate(p, &atom);
+    let mut q = univariate(q, &atom);
+    let content = common_divisor(&primitive(&mut p), &primitive(&mut q));
+    if p.len() < q.len() {
+        ::std::mem::swap(&mut p, &mut q);
+    }
+    while q.len() > 1 {
+        let mut remainder = pseudo_remainder(&p, &q);
+        primitive(&mut remainder);
+        p = q;
+        q = remainder;
+    }
+    if q.is_empty() {
+        let mut result = Polynomial { monomials: Vec::new() };
+        for (i, c) in p.iter().enumerate() {
+            result += &(c * &Monomial {
+                coefficient: C::one(),
+                powers: vec![(atom.clone(), P::from_usize(i).unwrap())],
+            });
+        }
+        &content * &result
+    } else {
+        content
+    }
+}
+
+/// Returns the coefficients of the polynomial viewed as a univariate polynomial in `atom`,
+/// such that the `i`-th entry is the coefficient of `atom^i`.
+fn univariate<I, C, P>(polynomial: &Polynomial<I, C, P>,
+                       atom: &Composite<I, C, P>)
+                       -> Vec<Polynomial<I, C, P>>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    let mut result: Vec<Polynomial<I, C, P>> = Vec::new();
+    for m in &polynomial.monomials {
+        let mut rest = m.clone();
+        let mut power = 0;
+        if let Some(i) = m.powers.iter().position(|(c, _)| c == atom) {
+            power = m.powers[i].1.to_usize().unwrap();
+            rest.powers.remove(i);
+        }
+        while result.len() <= power {
+            result.push(Polynomial { monomials: Vec::new() });
+        }
+        result[power] += &rest;
+    }
+    result
+}
+
+/// Divides the coefficients of a univariate polynomial by their greatest common divisor,
+/// which is returned.
+fn primitive<I, C, P>(coefficients: &mut [Polynomial<I, C, P>]) -> Polynomial<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    let mut content = Polynomial { monomials: Vec::new() };
+    for c in coefficients.iter() {
+        content = common_divisor(&content, c);
+    }
+    if !content.monomials.is_empty() {
+        for c in coefficients.iter_mut() {
+            *c = c.checked_div(&content).unwrap();
+        }
+    }
+    content
+}
+
+/// Computes the pseudo-remainder of the division of two univariate polynomials, which is
+/// the remainder of `lc(q)^k p` divided by `q` and has no fractions.
+fn pseudo_remainder<I, C, P>(p: &[Polynomial<I, C, P>],
+                             q: &[Polynomial<I, C, P>])
+                             -> Vec<Polynomial<I, C, P>>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    let mut result = p.to_vec();
+    let lead = &q[q.len() - 1];
+    while result.len() >= q.len() {
+        let top = result.pop().unwrap();
+        let shift = result.len() + 1 - q.len();
+        for c in &mut result {
+            *c *= lead;
+        }
+        for (i, c) in q[..q.len() - 1].iter().enumerate() {
+            result[shift + i] -= &(&top * c);
+        }
+        while result.last().is_some_and(|c| c.monomials.is_empty()) {
+            result.pop();
+        }
+    }
+    result
+}
+
+/// Computes the determinant of a square matrix of polynomials using the
+/// fraction-free Bareiss algorithm, where all of the divisions are exact.
+fn determinant<I, C, P>(mut matrix: Vec<Vec<Polynomial<I, C, P>>>) -> Polynomial<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    let n = matrix.len();
+    let mut negate = false;
+    let mut previous = Polynomial::from(C::one());
+    for k in 0..n {
+        if matrix[k][k].monomials.is_empty() {
+            match (k + 1..n).find(|&i| !matrix[i][k].monomials.is_empty()) {
+                Some(i) => {
+                    matrix.swap(k, i);
+                    negate = !negate;
+                }
+                None => return Polynomial { monomials: Vec::new() },
+            }
+        }
+        for i in k + 1..n {
+            for j in k + 1..n {
+                let value = &(&matrix[i][j] * &matrix[k][k]) - &(&matrix[i][k] * &matrix[k][j]);
+                matrix[i][j] = value.checked_div(&previous).unwrap();
+            }
+        }
+        previous = matrix[k][k].clone();
+    }
+    if negate { -previous } else { previous }
 }
 
 impl<I, C, P> ::std::fmt::Display for Polynomial<I, C, P>
@@ -303,8 +701,15 @@ impl<'a, I, C, P> MulAssign<&'a Monomial<I, C, P>> for Polynomial<I, C, P>
           C: Coefficient,
           P: Power {
     fn mul_assign(&mut self, rhs: &'a Monomial<I, C, P>) {
+        let mut changed = false;
         for m in &mut self.monomials {
-            *m *= rhs;
+            if m.multiply(rhs) {
+                changed = true;
+            }
+        }
+        // Cancelling or merging powers can change the relative order of the monomials
+        if changed {
+            self.monomials.sort_by(|a, b| Ord::cmp(b, a));
         }
     }
 }
@@ -493,7 +898,7 @@ impl<'a, 'b, I, C, P> Div<&'b Monomial<I, C, P>> for &'a Polynomial<I, C, P>
           P: Power {
     type Output = Polynomial<I, C, P>;
     fn div(self, rhs: &'b Monomial<I, C, P>) -> Self::Output {
-        match self.checked_div(&rhs.into()) {
+        match self.checked_div(&rhs.into()).or_else(|| self.laurent_div(rhs)) {
             Some(result) => result,
             None => panic!("Trying to divide {} by {} which is not possible.", self, rhs),
         }
@@ -563,7 +968,11 @@ impl<'a, 'b, I, C, P> Div<&'b Polynomial<I, C, P>> for &'a Polynomial<I, C, P>
           P: Power {
     type Output = Polynomial<I, C, P>;
     fn div(self, rhs: &'b Polynomial<I, C, P>) -> Self::Output {
-        match self.checked_div(rhs) {
+        let laurent = || match rhs.monomials[..] {
+            [ref m] => self.laurent_div(m),
+            _ => None,
+        };
+        match self.checked_div(rhs).or_else(laurent) {
             Some(result) => result,
             None => panic!("Trying to divide {} by {} which is not possible.", self, rhs),
         }
@@ -987,7 +1396,7 @@ impl<'a, I, C, P> SubAssign<&'a Polynomial<I, C, P>> for Polynomial<I, C, P>
             } else {
                 self.monomials.insert(i1, -&(rhs.monomials[i2]));
                 i1 += 1;
-                i2 += 2;
+                i2 += 1;
             }
         }
         while i2 < rhs.monomials.len() {
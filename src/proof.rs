@@ -0,0 +1,444 @@
+use std::collections::{HashMap, HashSet};
+use std::convert::AsRef;
+
+use traits::*;
+use monomial::Monomial;
+use polynomial::Polynomial;
+use composite::Composite;
+use condition::Relation;
+use assumptions::Assumptions;
+use functions::{reduce_with, is_non_negative, multiply_power};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// The outcome of an attempt to prove a symbolic inequality or equivalence.
+pub enum Proof<I, C>
+    where I: Id,
+          C: Coefficient {
+    /// The relation holds for all values of the variables allowed by the assumptions.
+    Proven,
+    /// The relation does not hold for the contained assignment of values to the variables.
+    Disproven(HashMap<I, C>),
+    /// Neither a proof nor a counterexample could be found.
+    Unknown,
+}
+
+impl<I, C> Proof<I, C>
+    where I: Id,
+          C: Coefficient {
+    /// Checks whether the relation was proven.
+    pub fn is_proven(&self) -> bool {
+        *self == Proof::Proven
+    }
+
+    /// Returns the counterexample if the relation was disproven.
+    pub fn counterexample(&self) -> Option<&HashMap<I, C>> {
+        match *self {
+            Proof::Disproven(ref values) => Some(values),
+            _ => None,
+        }
+    }
+}
+
+/// The maximum depth of nested case splits attempted when proving an inequality.
+const MAX_CASE_SPLITS: usize = 4;
+
+/// The maximum number of systematically chosen assignments evaluated
+/// when searching for a counterexample.
+const MAX_SAMPLES: usize = 10000;
+
+/// The number of random assignments evaluated when searching for a counterexample.
+const MAX_RANDOM_SAMPLES: usize = 1000;
+
+/// The maximum magnitude of the random values (away from any bound),
+/// small enough to avoid overflows when evaluating polynomials of a moderate degree.
+const RANDOM_RANGE: i64 = 20;
+
+/// Tries to prove that `left >= right` for all values of the variables allowed by the assumptions.
+///
+/// The proof is based on a sign analysis of the difference `left - right` (after shifting
+/// the bounded variables to be non-negative), on its interval bounds when all of its variables
+/// are bounded and on case splits over `max`, `min`, `abs`, `sign` and `select`.
+/// Failing that, a counterexample is searched for among the values allowed by the assumptions.
+pub fn prove_ge<I, C, P, T1, T2>(left: T1,
+                                 right: T2,
+                                 assumptions: &Assumptions<I, C>)
+                                 -> Proof<I, C>
+    where I: Id,
+          C: Coefficient,
+          P: Power,
+          T1: AsRef<Polynomial<I, C, P>>,
+          T2: AsRef<Polynomial<I, C, P>> {
+    let difference = left.as_ref() - right.as_ref();
+    if prove_non_negative(&difference, &[], assumptions, MAX_CASE_SPLITS) {
+        return Proof::Proven;
+    }
+    let mut ids = HashSet::new();
+    difference.unique_identifiers(&mut ids);
+    let negative = |values: &HashMap<I, C>| match difference.eval(values) {
+        Ok(value) => value < C::zero(),
+        Err(_) => false,
+    };
+    match find_assignment(ids, assumptions, negative) {
+        Some(values) => Proof::Disproven(values),
+        None => Proof::Unknown,
+    }
+}
+
+/// Checks whether the two polynomials are equal for all values of their variables.
+///
+/// See `equivalent_with` for more.
+pub fn equivalent<I, C, P, T1, T2>(left: T1, right: T2) -> Proof<I, C>
+    where I: Id,
+          C: Coefficient,
+          P: Power,
+          T1: AsRef<Polynomial<I, C, P>>,
+          T2: AsRef<Polynomial<I, C, P>> {
+    equivalent_with(left, right, &Assumptions::new())
+}
+
+/// Checks whether the two polynomials are equal for all values of their variables
+/// allowed by the assumptions.
+///
+/// The equality is proven if their difference simplifies to zero or is both non-negative
+/// and non-positive (e.g. `min(a, b) + max(a, b) = a + b`). Otherwise the two are compared
+/// at many values, which by the Schwartz-Zippel lemma detects different polynomials with
+/// a high probability, returning a counterexample when they differ or `Unknown` when they
+/// agreed on all of them.
+pub fn equivalent_with<I, C, P, T1, T2>(left: T1,
+                                        right: T2,
+                                        assumptions: &Assumptions<I, C>)
+                                        -> Proof<I, C>
+    where I: Id,
+          C: Coefficient,
+          P: Power,
+          T1: AsRef<Polynomial<I, C, P>>,
+          T2: AsRef<Polynomial<I, C, P>> {
+    let left = left.as_ref();
+    let right = right.as_ref();
+    let difference = reduce_with(left - right, &HashMap::new(), assumptions);
+    if difference.monomials.is_empty() ||
+       (prove_non_negative(&difference, &[], assumptions, MAX_CASE_SPLITS) &&
+        prove_non_negative(&-&difference, &[], assumptions, MAX_CASE_SPLITS)) {
+        return Proof::Proven;
+    }
+    let mut ids = HashSet::new();
+    left.unique_identifiers(&mut ids);
+    right.unique_identifiers(&mut ids);
+    let different = |values: &HashMap<I, C>| match (left.eval(values), right.eval(values)) {
+        (Ok(v_left), Ok(v_right)) => v_left != v_right,
+        _ => false,
+    };
+    match find_assignment(ids, assumptions, different) {
+        Some(values) => Proof::Disproven(values),
+        None => Proof::Unknown,
+    }
+}
+
+/// Checks whether the polynomial is non-negative, given that all of the `facts` are.
+fn prove_non_negative<I, C, P>(polynomial: &Polynomial<I, C, P>,
+                               facts: &[Polynomial<I, C, P>],
+                               assumptions: &Assumptions<I, C>,
+                               depth: usize)
+                               -> bool
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    // Since x >= k*f >= 0 implies that x >= 0 for any positive k
+    let by_fact = |fact: &Polynomial<I, C, P>| {
+        is_non_negative(&(polynomial - fact * fact_multiple(polynomial, fact)), assumptions)
+    };
+    let all_facts = facts.iter().fold(polynomial.clone(), |result, fact| result - fact);
+    if is_non_negative(polynomial, assumptions) || facts.iter().any(by_fact) ||
+       (facts.len() > 1 && is_non_negative(&all_facts, assumptions)) {
+        return true;
+    }
+    if let Some((low, _)) = bounded_interval(polynomial, assumptions) {
+        if low >= C::zero() {
+            return true;
+        }
+    }
+    if depth == 0 {
+        return false;
+    }
+    match case_split(polynomial) {
+        Some(cases) => {
+            cases.into_iter().all(|(polynomial, case_facts)| {
+                let mut facts = facts.to_vec();
+                facts.extend(case_facts);
+                prove_non_negative(&polynomial, &facts, assumptions, depth - 1)
+            })
+        }
+        None => false,
+    }
+}
+
+/// Returns how many times the leading term of the `fact` fits into the one of the polynomial,
+/// or one if they are not alike.
+fn fact_multiple<I, C, P>(polynomial: &Polynomial<I, C, P>, fact: &Polynomial<I, C, P>) -> C
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    match (polynomial.monomials.first(), fact.monomials.first()) {
+        (Some(m), Some(f)) if m.powers == f.powers => {
+            let k = m.coefficient.clone() / f.coefficient.clone();
+            if k > C::one() { k } else { C::one() }
+        }
+        _ => C::one(),
+    }
+}
+
+/// Computes the interval bounds of the polynomial if all of its variables are bounded
+/// from both sides by the assumptions.
+fn bounded_interval<I, C, P>(polynomial: &Polynomial<I, C, P>,
+                             assumptions: &Assumptions<I, C>)
+                             -> Option<(C, C)>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    let mut ids = HashSet::new();
+    polynomial.unique_identifiers(&mut ids);
+    let mut intervals = HashMap::new();
+    for id in ids {
+        match (assumptions.lower_bound(&id), assumptions.upper_bound(&id)) {
+            (Some(lower), Some(upper)) => intervals.insert(id, (lower.clone(), upper.clone())),
+            _ => return None,
+        };
+    }
+    polynomial.eval_interval(&intervals).ok()
+}
+
+/// A single case of a split, holding the polynomial within the case together with
+/// the facts (polynomials known to be non-negative) defining it.
+type Case<I, C, P> = (Polynomial<I, C, P>, Vec<Polynomial<I, C, P>>);
+
+/// Splits the polynomial into cases based on its first `max`, `min`, `abs`, `sign` or `select`,
+/// replacing it with each of the values it can take.
+fn case_split<I, C, P>(polynomial: &Polynomial<I, C, P>) -> Option<Vec<Case<I, C, P>>>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    for m in &polynomial.monomials {
+        for (c, _) in &m.powers {
+            let cases: Vec<Case<I, C, P>> = match *c {
+                Composite::Max(ref args) |
+                Composite::Min(ref args) => {
+                    // Each argument is a case, in which it is no smaller (larger) than the rest
+                    args.iter()
+                        .enumerate()
+                        .map(|(i, arg)| {
+                            let facts = args.iter()
+                                .enumerate()
+                                .filter(|&(j, _)| i != j)
+                                .map(|(_, other)| match *c {
+                                    Composite::Max(_) => &**arg - &**other,
+                                    _ => &**other - &**arg,
+                                })
+                                .collect();
+                            ((**arg).clone(), facts)
+                        })
+                        .collect()
+                }
+                Composite::Abs(ref x) => {
+                    vec![((**x).clone(), vec![(**x).clone()]), (-&**x, vec![-&**x])]
+                }
+                Composite::Sign(ref x) => {
+                    vec![
+                        (Polynomial::from(C::one()), vec![&**x - C::one()]),
+                        (Polynomial::from(C::zero()), vec![(**x).clone(), -&**x]),
+                        (Polynomial::from(-C::one()), vec![-&**x - C::one()]),
+                    ]
+                }
+                Composite::Select(ref cond, ref then, ref otherwise) => {
+                    let difference = &cond.left - &cond.right;
+                    let negated = cond.relation.negate();
+                    vec![
+                        ((**then).clone(), relation_facts(cond.relation, &difference)),
+                        ((**otherwise).clone(), relation_facts(negated, &difference)),
+                    ]
+                }
+                _ => continue,
+            };
+            let cases = cases.into_iter()
+                .map(|(value, facts)| (replace_composite(polynomial, c, &value), facts))
+                .collect();
+            return Some(cases);
+        }
+    }
+    None
+}
+
+/// Returns the facts (polynomials known to be non-negative) equivalent to
+/// `difference <relation> 0` over the integers, or as many of them as can be expressed.
+fn relation_facts<I, C, P>(relation: Relation,
+                           difference: &Polynomial<I, C, P>)
+                           -> Vec<Polynomial<I, C, P>>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    match relation {
+        Relation::Equal => vec![difference.clone(), -difference],
+        Relation::NotEqual => Vec::new(),
+        Relation::Less => vec![-difference - C::one()],
+        Relation::LessOrEqual => vec![-difference],
+        Relation::Greater => vec![difference - C::one()],
+        Relation::GreaterOrEqual => vec![difference.clone()],
+    }
+}
+
+/// Replaces every occurrence of the `target` composite in the polynomial with `value`.
+fn replace_composite<I, C, P>(polynomial: &Polynomial<I, C, P>,
+                              target: &Composite<I, C, P>,
+                              value: &Polynomial<I, C, P>)
+                              -> Polynomial<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    let mut result = Polynomial::<I, C, P> { monomials: Vec::new() };
+    for m in &polynomial.monomials {
+        let mut product = Polynomial::from(m.coefficient.clone());
+        let (negative, positive): (Vec<_>, Vec<_>) =
+            m.powers.iter().partition(|&(_, p)| *p < P::zero());
+        for (c, p) in positive.into_iter().chain(negative) {
+            let factor = if c == target {
+                value.clone()
+            } else {
+                Polynomial::from(Monomial {
+                    coefficient: C::one(),
+                    powers: vec![(c.clone(), P::one())],
+                })
+            };
+            multiply_power(&mut product, c, &factor, p);
+        }
+        result += &product;
+    }
+    result
+}
+
+/// Searches for an assignment of values allowed by the assumptions to all of the variables
+/// in `ids` which satisfies the `predicate`, trying the small values and the ones closest
+/// to the bounds first and random values after that.
+fn find_assignment<I, C, F>(ids: HashSet<I>,
+                            assumptions: &Assumptions<I, C>,
+                            predicate: F)
+                            -> Option<HashMap<I, C>>
+    where I: Id,
+          C: Coefficient,
+          F: Fn(&HashMap<I, C>) -> bool {
+    let mut ids: Vec<I> = ids.into_iter().collect();
+    ids.sort();
+    let candidates: Vec<Vec<C>> = ids.iter().map(|id| sample_values(id, assumptions)).collect();
+    if candidates.iter().any(|values| values.is_empty()) {
+        return None;
+    }
+    let mut indices = vec![0; ids.len()];
+    let mut values = HashMap::new();
+    for _ in 0..MAX_SAMPLES {
+        for (i, id) in ids.iter().enumerate() {
+            values.insert(id.clone(), candidates[i][indices[i]].clone());
+        }
+        if predicate(&values) {
+            return Some(values);
+        }
+        // Move on to the next combination of the candidate values
+        let mut i = 0;
+        while i < ids.len() {
+            indices[i] += 1;
+            if indices[i] < candidates[i].len() {
+                break;
+            }
+            indices[i] = 0;
+            i += 1;
+        }
+        if i == ids.len() {
+            break;
+        }
+    }
+    if ids.is_empty() {
+        return None;
+    }
+    let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+    for _ in 0..MAX_RANDOM_SAMPLES {
+        for id in &ids {
+            if let Some(value) = random_value(id, assumptions, &mut rng) {
+                values.insert(id.clone(), value);
+            }
+        }
+        if predicate(&values) {
+            return Some(values);
+        }
+    }
+    None
+}
+
+/// Returns a few values of the variable allowed by the assumptions,
+/// the small ones and those closest to its bounds.
+fn sample_values<I, C>(id: &I, assumptions: &Assumptions<I, C>) -> Vec<C>
+    where I: Id,
+          C: Coefficient {
+    // All of the values are sampled in units of the known divisor
+    let k = assumptions.divisor(id);
+    let lower = assumptions.lower_bound(id).map(|lower| -(-lower.clone()).floor_div(&k));
+    let upper = assumptions.upper_bound(id).map(|upper| upper.floor_div(&k));
+    let mut units: Vec<C> = (-4..5).map(|unit| C::from_i64(unit).unwrap()).collect();
+    let mut offset = C::zero();
+    for _ in 0..3 {
+        if let Some(ref lower) = lower {
+            units.push(lower.clone() + offset.clone());
+        }
+        if let Some(ref upper) = upper {
+            units.push(upper.clone() - offset.clone());
+        }
+        offset += C::one();
+    }
+    units.retain(|unit| {
+        lower.as_ref().is_none_or(|lower| unit >= lower) &&
+        upper.as_ref().is_none_or(|upper| unit <= upper)
+    });
+    units.sort();
+    units.dedup();
+    units.into_iter().map(|unit| unit * k.clone()).collect()
+}
+
+/// Returns a random value of the variable allowed by the assumptions,
+/// or None if it does not fit into the coefficient type.
+fn random_value<I, C>(id: &I, assumptions: &Assumptions<I, C>, rng: &mut XorShift) -> Option<C>
+    where I: Id,
+          C: Coefficient {
+    // All of the values are sampled in units of the known divisor
+    let k = assumptions.divisor(id);
+    let lower = assumptions.lower_bound(id).map(|lower| {
+        let unit = lower.floor_div(&k);
+        if unit.clone() * k.clone() == *lower { unit } else { unit + C::one() }
+    });
+    let upper = assumptions.upper_bound(id).map(|upper| upper.floor_div(&k));
+    let range = C::from_i64(RANDOM_RANGE).unwrap();
+    let width = range.clone() + range.clone();
+    // Near the limits of the type the range is cut short instead of overflowing
+    let (low, high) = match (lower, upper) {
+        (Some(lower), Some(upper)) => {
+            let high = match lower.checked_add(&width) {
+                Some(high) => ::std::cmp::min(upper, high),
+                None => upper,
+            };
+            (lower, high)
+        }
+        (Some(lower), None) => (lower.clone(), lower.checked_add(&width).unwrap_or(lower)),
+        (None, Some(upper)) => (upper.checked_sub(&width).unwrap_or_else(|| upper.clone()), upper),
+        (None, None) => (-range.clone(), range),
+    };
+    let span = high.checked_sub(&low)?.checked_add(&C::one())?.as_usize()? as u64;
+    low.checked_add(&C::from_u64(rng.next_u64() % span).unwrap())?.checked_mul(&k)
+}
+
+/// A minimal xorshift pseudo-random number generator, used for sampling values.
+struct XorShift(u64);
+
+impl XorShift {
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
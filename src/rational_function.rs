@@ -0,0 +1,318 @@
+use std::ops::{AddAssign, SubAssign, MulAssign, DivAssign, Add, Neg, Sub, Mul, Div};
+use std::collections::HashMap;
+
+use traits::*;
+use polynomial::Polynomial;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+/// A symbolic rational function represented as the quotient `numerator / denominator`.
+///
+/// The two are kept reduced by their greatest common divisor, with the denominator having
+/// a positive leading coefficient, such that equal rational functions are represented equally.
+pub struct RationalFunction<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    /// The numerator polynomial.
+    pub numerator: Polynomial<I, C, P>,
+    /// The denominator polynomial, which is never zero.
+    pub denominator: Polynomial<I, C, P>,
+}
+
+impl<I, C, P> RationalFunction<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    /// Creates the reduced rational function `numerator / denominator`.
+    /// Any negative powers are moved into the denominator, such that both are polynomials.
+    /// Panics if the denominator is zero.
+    pub fn new(numerator: Polynomial<I, C, P>, denominator: Polynomial<I, C, P>) -> Self {
+        if denominator.monomials.is_empty() {
+            panic!("Trying to divide {} by {} which is not possible.", numerator, denominator);
+        }
+        if numerator.monomials.is_empty() {
+            return RationalFunction {
+                numerator,
+                denominator: Polynomial::from(C::one()),
+            };
+        }
+        let multiplier = numerator.common_denominator() * &denominator.common_denominator();
+        let numerator = &numerator * &multiplier;
+        let denominator = &denominator * &multiplier;
+        let divisor = numerator.greatest_common_divisor(&denominator);
+        let numerator = numerator.checked_div(&divisor).unwrap();
+        let denominator = denominator.checked_div(&divisor).unwrap();
+        if denominator.monomials[0].coefficient < C::zero() {
+            RationalFunction {
+                numerator: -numerator,
+                denominator: -denominator,
+            }
+        } else {
+            RationalFunction {
+                numerator,
+                denominator,
+            }
+        }
+    }
+
+    /// Evaluates the `RationalFunction` given the provided mapping of identifiers to value
+    /// assignments. Fails if the denominator is zero or does not divide the numerator.
+    pub fn eval(&self, values: &HashMap<I, C>) -> Result<C, (I, String)> {
+        let numerator = self.numerator.eval(values)?;
+        let denominator = self.denominator.eval(values)?;
+        if denominator == C::zero() {
+            return Err((I::default(), "Attempting division by zero.".into()));
+        }
+        if let Some(value) = numerator.exact_div(&denominator) {
+            Ok(value)
+        } else {
+            Err((I::default(), format!("The value of {} is not an integer.", self)))
+        }
+    }
+
+    /// Returns the `Polynomial` equal to the rational function if the denominator divides
+    /// the numerator, otherwise None.
+    pub fn to_polynomial(&self) -> Option<Polynomial<I, C, P>> {
+        self.numerator.checked_div(&self.denominator)
+    }
+
+    /// Returns a code equivalent string representation of the `RationalFunction`.
+    /// The `format` specifies a function how to render the identifiers.
+    pub fn to_code<F>(&self, format: &F) -> String
+        where F: ::std::ops::Fn(I) -> String {
+        if self.denominator == C::one() {
+            self.numerator.to_code(format)
+        } else {
+            format!(
+                "({}) / ({})",
+                self.numerator.to_code(format),
+                self.denominator.to_code(format)
+            )
+        }
+    }
+}
+
+impl<I, C, P> ::std::fmt::Display for RationalFunction<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        if self.denominator == C::one() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "({}) / ({})", self.numerator, self.denominator)
+        }
+    }
+}
+
+impl<I, C, P> From<C> for RationalFunction<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    fn from(other: C) -> Self {
+        if other == C::zero() {
+            RationalFunction::from(Polynomial { monomials: Vec::new() })
+        } else {
+            RationalFunction::from(Polynomial::from(other))
+        }
+    }
+}
+
+impl<I, C, P> From<Polynomial<I, C, P>> for RationalFunction<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    fn from(other: Polynomial<I, C, P>) -> Self {
+        RationalFunction::new(other, Polynomial::from(C::one()))
+    }
+}
+
+impl<'a, I, C, P> From<&'a Polynomial<I, C, P>> for RationalFunction<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    fn from(other: &'a Polynomial<I, C, P>) -> Self {
+        RationalFunction::from(other.clone())
+    }
+}
+
+impl<'a, 'b, I, C, P> Add<&'b RationalFunction<I, C, P>> for &'a RationalFunction<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    type Output = RationalFunction<I, C, P>;
+    fn add(self, rhs: &'b RationalFunction<I, C, P>) -> Self::Output {
+        RationalFunction::new(
+            &self.numerator * &rhs.denominator + &rhs.numerator * &self.denominator,
+            &self.denominator * &rhs.denominator,
+        )
+    }
+}
+
+impl<'a, 'b, I, C, P> Sub<&'b RationalFunction<I, C, P>> for &'a RationalFunction<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    type Output = RationalFunction<I, C, P>;
+    fn sub(self, rhs: &'b RationalFunction<I, C, P>) -> Self::Output {
+        RationalFunction::new(
+            &self.numerator * &rhs.denominator - &rhs.numerator * &self.denominator,
+            &self.denominator * &rhs.denominator,
+        )
+    }
+}
+
+impl<'a, 'b, I, C, P> Mul<&'b RationalFunction<I, C, P>> for &'a RationalFunction<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    type Output = RationalFunction<I, C, P>;
+    fn mul(self, rhs: &'b RationalFunction<I, C, P>) -> Self::Output {
+        RationalFunction::new(
+            &self.numerator * &rhs.numerator,
+            &self.denominator * &rhs.denominator,
+        )
+    }
+}
+
+impl<'a, 'b, I, C, P> Div<&'b RationalFunction<I, C, P>> for &'a RationalFunction<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    type Output = RationalFunction<I, C, P>;
+    fn div(self, rhs: &'b RationalFunction<I, C, P>) -> Self::Output {
+        RationalFunction::new(
+            &self.numerator * &rhs.denominator,
+            &self.denominator * &rhs.numerator,
+        )
+    }
+}
+
+impl<'a, I, C, P> Neg for &'a RationalFunction<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    type Output = RationalFunction<I, C, P>;
+    fn neg(self) -> Self::Output {
+        RationalFunction {
+            numerator: -&self.numerator,
+            denominator: self.denominator.clone(),
+        }
+    }
+}
+
+impl<I, C, P> Neg for RationalFunction<I, C, P>
+    where I: Id,
+          C: Coefficient,
+          P: Power {
+    type Output = RationalFunction<I, C, P>;
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+/// Implements the remaining owned and borrowed variants of an operator, as well as its
+/// assigning version, in terms of the one between two borrowed rational functions.
+macro_rules! impl_operator {
+    ( $trait_:ident, $method:ident, $assign_trait:ident, $assign_method:ident ) => {
+        impl<'b, I, C, P> $trait_<&'b RationalFunction<I, C, P>> for RationalFunction<I, C, P>
+            where I: Id, C: Coefficient, P: Power {
+            type Output = RationalFunction<I, C, P>;
+            fn $method(self, rhs: &'b RationalFunction<I, C, P>) -> Self::Output {
+                (&self).$method(rhs)
+            }
+        }
+        impl<'a, I, C, P> $trait_<RationalFunction<I, C, P>> for &'a RationalFunction<I, C, P>
+            where I: Id, C: Coefficient, P: Power {
+            type Output = RationalFunction<I, C, P>;
+            fn $method(self, rhs: RationalFunction<I, C, P>) -> Self::Output {
+                self.$method(&rhs)
+            }
+        }
+        impl<I, C, P> $trait_<RationalFunction<I, C, P>> for RationalFunction<I, C, P>
+            where I: Id, C: Coefficient, P: Power {
+            type Output = RationalFunction<I, C, P>;
+            fn $method(self, rhs: RationalFunction<I, C, P>) -> Self::Output {
+                (&self).$method(&rhs)
+            }
+        }
+        impl<'a, 'b, I, C, P> $trait_<&'b Polynomial<I, C, P>> for &'a RationalFunction<I, C, P>
+            where I: Id, C: Coefficient, P: Power {
+            type Output = RationalFunction<I, C, P>;
+            fn $method(self, rhs: &'b Polynomial<I, C, P>) -> Self::Output {
+                self.$method(&RationalFunction::from(rhs))
+            }
+        }
+        impl<'b, I, C, P> $trait_<&'b Polynomial<I, C, P>> for RationalFunction<I, C, P>
+            where I: Id, C: Coefficient, P: Power {
+            type Output = RationalFunction<I, C, P>;
+            fn $method(self, rhs: &'b Polynomial<I, C, P>) -> Self::Output {
+                (&self).$method(&RationalFunction::from(rhs))
+            }
+        }
+        impl<'a, I, C, P> $trait_<Polynomial<I, C, P>> for &'a RationalFunction<I, C, P>
+            where I: Id, C: Coefficient, P: Power {
+            type Output = RationalFunction<I, C, P>;
+            fn $method(self, rhs: Polynomial<I, C, P>) -> Self::Output {
+                self.$method(&RationalFunction::from(rhs))
+            }
+        }
+        impl<I, C, P> $trait_<Polynomial<I, C, P>> for RationalFunction<I, C, P>
+            where I: Id, C: Coefficient, P: Power {
+            type Output = RationalFunction<I, C, P>;
+            fn $method(self, rhs: Polynomial<I, C, P>) -> Self::Output {
+                (&self).$method(&RationalFunction::from(rhs))
+            }
+        }
+        impl<'a, I, C, P> $trait_<C> for &'a RationalFunction<I, C, P>
+            where I: Id, C: Coefficient, P: Power {
+            type Output = RationalFunction<I, C, P>;
+            fn $method(self, rhs: C) -> Self::Output {
+                self.$method(&RationalFunction::from(rhs))
+            }
+        }
+        impl<I, C, P> $trait_<C> for RationalFunction<I, C, P>
+            where I: Id, C: Coefficient, P: Power {
+            type Output = RationalFunction<I, C, P>;
+            fn $method(self, rhs: C) -> Self::Output {
+                (&self).$method(&RationalFunction::from(rhs))
+            }
+        }
+        impl<'b, I, C, P> $assign_trait<&'b RationalFunction<I, C, P>> for RationalFunction<I, C, P>
+            where I: Id, C: Coefficient, P: Power {
+            fn $assign_method(&mut self, rhs: &'b RationalFunction<I, C, P>) {
+                *self = (&*self).$method(rhs);
+            }
+        }
+        impl<I, C, P> $assign_trait<RationalFunction<I, C, P>> for RationalFunction<I, C, P>
+            where I: Id, C: Coefficient, P: Power {
+            fn $assign_method(&mut self, rhs: RationalFunction<I, C, P>) {
+                *self = (&*self).$method(&rhs);
+            }
+        }
+        impl<'b, I, C, P> $assign_trait<&'b Polynomial<I, C, P>> for RationalFunction<I, C, P>
+            where I: Id, C: Coefficient, P: Power {
+            fn $assign_method(&mut self, rhs: &'b Polynomial<I, C, P>) {
+                *self = (&*self).$method(rhs);
+            }
+        }
+        impl<I, C, P> $assign_trait<Polynomial<I, C, P>> for RationalFunction<I, C, P>
+            where I: Id, C: Coefficient, P: Power {
+            fn $assign_method(&mut self, rhs: Polynomial<I, C, P>) {
+                *self = (&*self).$method(&rhs);
+            }
+        }
+        impl<I, C, P> $assign_trait<C> for RationalFunction<I, C, P>
+            where I: Id, C: Coefficient, P: Power {
+            fn $assign_method(&mut self, rhs: C) {
+                *self = (&*self).$method(rhs);
+            }
+        }
+    };
+}
+
+impl_operator!(Add, add, AddAssign, add_assign);
+impl_operator!(Sub, sub, SubAssign, sub_assign);
+impl_operator!(Mul, mul, MulAssign, mul_assign);
+impl_operator!(Div, div, DivAssign, div_assign);
@@ -0,0 +1,75 @@
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+#[derive(Clone, Default, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "repr_c", repr(C))]
+/// An identifier of a user-defined function, which can be applied to polynomials.
+pub struct FnId(pub String);
+
+impl<'a> From<&'a str> for FnId {
+    fn from(name: &'a str) -> Self {
+        FnId(name.into())
+    }
+}
+
+impl From<String> for FnId {
+    fn from(name: String) -> Self {
+        FnId(name)
+    }
+}
+
+impl ::std::fmt::Display for FnId {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// The evaluation closure of a user-defined function over the coefficient type `C`.
+type Function<C> = Rc<dyn Fn(&[C]) -> Result<C, String>>;
+
+thread_local! {
+    /// All of the registered functions, keyed by their identifier and the type of
+    /// the coefficients they operate on.
+    static REGISTRY: RefCell<HashMap<(FnId, TypeId), Rc<dyn Any>>> = RefCell::new(HashMap::new());
+}
+
+/// Registers the closure `function` used to evaluate the user-defined function `id`
+/// over the coefficient type `C`, replacing any previously registered one.
+/// Registering it over `f64` as well allows it to be used by `eval_f64`.
+///
+/// The registry is local to the current thread.
+pub fn register_function<C, F>(id: FnId, function: F)
+    where C: 'static,
+          F: Fn(&[C]) -> Result<C, String> + 'static {
+    let function: Function<C> = Rc::new(function);
+    REGISTRY.with(|registry| {
+        registry.borrow_mut().insert((id, TypeId::of::<C>()), Rc::new(function));
+    });
+}
+
+/// Removes the user-defined function `id` over the coefficient type `C` from the registry.
+/// Returns `true` if the function was registered.
+pub fn unregister_function<C>(id: &FnId) -> bool
+    where C: 'static {
+    REGISTRY.with(|registry| {
+        registry.borrow_mut().remove(&(id.clone(), TypeId::of::<C>())).is_some()
+    })
+}
+
+/// Evaluates the user-defined function `id` at the given arguments.
+pub fn call_function<C>(id: &FnId, arguments: &[C]) -> Result<C, String>
+    where C: 'static {
+    let function = REGISTRY.with(|registry| {
+        registry
+            .borrow()
+            .get(&(id.clone(), TypeId::of::<C>()))
+            .and_then(|f| f.downcast_ref::<Function<C>>().cloned())
+    });
+    match function {
+        Some(function) => function(arguments),
+        None => Err(format!("The function {} is not registered.", id)),
+    }
+}
@@ -1,5 +1,9 @@
 use std::ops::{AddAssign, MulAssign, SubAssign, DivAssign, Neg};
-use num::{Integer, CheckedDiv, One, Zero, Unsigned};
+use num::{Integer, Num, One, Zero};
+use num::bigint::BigInt;
+use num::rational::Ratio;
+use num::traits::NumAssign;
+use num::{CheckedAdd, CheckedSub, CheckedMul};
 
 /// A trait specifying all the bounds an `Id` type should meet.
 ///
@@ -20,7 +24,6 @@ pub trait Power
     : Integer
     + One
     + Zero
-    + Unsigned
     + ::num::ToPrimitive
     + ::num::FromPrimitive
     + Clone
@@ -33,7 +36,6 @@ impl<T> Power for T
     where T: Integer
                  + One
                  + Zero
-                 + Unsigned
                  + ::num::ToPrimitive
                  + ::num::FromPrimitive
                  + Clone
@@ -44,13 +46,22 @@ impl<T> Power for T
 
 /// A trait specifying all the bounds a `Coefficient` type should meet.
 ///
+/// The coefficients can be either integers or come from a field, such as the rationals
+/// `num::rational::Ratio<i64>`, where every division by a non-zero value is exact.
+/// In both cases the variables are assumed to take integer values.
+///
+/// It is implemented for all signed primitive integers, `BigInt`, `Ratio<T>` and `ModInt<P>`.
+/// It is not implemented automatically for every integer type, hence other integer types
+/// have to implement it by hand. They only need to provide `as_usize` and `as_f64` (e.g. with
+/// `ToPrimitive`), as the defaults of the other methods below are derived from the truncating
+/// `/` and `%`. Fields have to override at least `exact_div`, `floor_div` and
+/// `greatest_common_divisor`.
+///
 /// See the module-level documentation for more.
 pub trait Coefficient
-    : Integer
-    + One
-    + ::num::ToPrimitive
+    : Num
+    + Ord
     + ::num::FromPrimitive
-    + CheckedDiv
     + AddAssign<Self>
     + SubAssign<Self>
     + MulAssign<Self>
@@ -58,21 +69,185 @@ pub trait Coefficient
     + Neg<Output = Self>
     + Clone
     + ::std::fmt::Display
-    + ::std::fmt::Debug {
+    + ::std::fmt::Debug
+    + 'static {
+    /// Returns the quotient `self / other` if it is exact, otherwise None.
+    fn exact_div(&self, other: &Self) -> Option<Self> {
+        if *other == Self::zero() {
+            return None;
+        }
+        let quotient = self.clone() / other.clone();
+        if quotient.clone() * other.clone() == *self { Some(quotient) } else { None }
+    }
+
+    /// Returns the floor of the quotient `self / other`, which is always an integer.
+    fn floor_div(&self, other: &Self) -> Self {
+        let quotient = self.clone() / other.clone();
+        let remainder = self.clone() - quotient.clone() * other.clone();
+        if remainder != Self::zero() && (remainder < Self::zero()) != (*other < Self::zero()) {
+            quotient - Self::one()
+        } else {
+            quotient
+        }
+    }
+
+    /// Returns the remainder `self - other * floor(self / other)`,
+    /// which has the same sign as `other`.
+    fn floor_mod(&self, other: &Self) -> Self {
+        self.clone() - other.clone() * self.floor_div(other)
+    }
+
+    /// Returns the non-negative greatest common divisor of the two values. For rationals this
+    /// is the greatest value which divides both of them an integer number of times.
+    fn greatest_common_divisor(&self, other: &Self) -> Self {
+        let mut a = if *self < Self::zero() { -self.clone() } else { self.clone() };
+        let mut b = if *other < Self::zero() { -other.clone() } else { other.clone() };
+        while b != Self::zero() {
+            let remainder = a % b.clone();
+            a = b;
+            b = if remainder < Self::zero() { -remainder } else { remainder };
+        }
+        a
+    }
+
+    /// Returns the non-negative least common multiple of the two values.
+    fn least_common_multiple(&self, other: &Self) -> Self {
+        if *self == Self::zero() || *other == Self::zero() {
+            return Self::zero();
+        }
+        let product = self.clone() * other.clone() / self.greatest_common_divisor(other);
+        if product < Self::zero() { -product } else { product }
+    }
+
+    /// Returns `self + other`, or None if it overflows. By default it never fails,
+    /// as for the types with arbitrary precision or modular arithmetic.
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(self.clone() + other.clone())
+    }
+
+    /// Returns `self - other`, or None if it overflows.
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Some(self.clone() - other.clone())
+    }
+
+    /// Returns `self * other`, or None if it overflows.
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        Some(self.clone() * other.clone())
+    }
+
+    /// Returns the value as a `usize` if it is a non-negative integer which fits in one.
+    fn as_usize(&self) -> Option<usize>;
+
+    /// Returns the closest `f64` to the value, as used by `eval_f64`.
+    fn as_f64(&self) -> f64;
 }
 
-impl<T> Coefficient for T
-    where T: Integer
-                 + One
-                 + ::num::ToPrimitive
-                 + ::num::FromPrimitive
-                 + CheckedDiv
-                 + AddAssign<T>
-                 + SubAssign<T>
-                 + MulAssign<T>
-                 + DivAssign<T>
+/// A trait for the `Coefficient` types which are integers, as required by the few functions
+/// computing with fractions of them (e.g. `sum_over`).
+pub trait IntegerCoefficient: Coefficient + Integer + ::num::ToPrimitive {}
+
+impl<T> IntegerCoefficient for T where T: Coefficient + Integer + ::num::ToPrimitive {}
+
+macro_rules! impl_coefficient {
+    ( $( $type_:ty ),* ) => {$(
+        impl Coefficient for $type_ {
+            fn exact_div(&self, other: &Self) -> Option<Self> {
+                if other.is_zero() {
+                    return None;
+                }
+                let (d, rem) = self.div_rem(other);
+                if rem.is_zero() { Some(d) } else { None }
+            }
+
+            fn floor_div(&self, other: &Self) -> Self {
+                self.div_floor(other)
+            }
+
+            fn greatest_common_divisor(&self, other: &Self) -> Self {
+                self.gcd(other)
+            }
+
+            fn checked_add(&self, other: &Self) -> Option<Self> {
+                CheckedAdd::checked_add(self, other)
+            }
+
+            fn checked_sub(&self, other: &Self) -> Option<Self> {
+                CheckedSub::checked_sub(self, other)
+            }
+
+            fn checked_mul(&self, other: &Self) -> Option<Self> {
+                CheckedMul::checked_mul(self, other)
+            }
+
+            fn as_usize(&self) -> Option<usize> {
+                ::num::ToPrimitive::to_usize(self)
+            }
+
+            fn as_f64(&self) -> f64 {
+                ::num::ToPrimitive::to_f64(self).unwrap_or(f64::NAN)
+            }
+        }
+    )*};
+}
+
+impl_coefficient!(i128, i64, i32, i16, i8, isize, BigInt);
+
+impl<T> Coefficient for Ratio<T>
+    where T: Clone
+                 + Integer
+                 + NumAssign
                  + Neg<Output = T>
-                 + Clone
+                 + CheckedAdd
+                 + CheckedSub
+                 + CheckedMul
+                 + ::num::ToPrimitive
                  + ::std::fmt::Display
-                 + ::std::fmt::Debug {
+                 + ::std::fmt::Debug
+                 + 'static,
+          Ratio<T>: ::num::FromPrimitive {
+    fn exact_div(&self, other: &Self) -> Option<Self> {
+        if *other == Self::zero() {
+            None
+        } else {
+            Some(self.clone() / other.clone())
+        }
+    }
+
+    fn floor_div(&self, other: &Self) -> Self {
+        (self.clone() / other.clone()).floor()
+    }
+
+    fn greatest_common_divisor(&self, other: &Self) -> Self {
+        // gcd(a / b, c / d) = gcd(ad, cb) / bd
+        let numerator = (self.numer().clone() * other.denom().clone())
+            .gcd(&(other.numer().clone() * self.denom().clone()));
+        Ratio::new(numerator, self.denom().clone() * other.denom().clone())
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        CheckedAdd::checked_add(self, other)
+    }
+
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        CheckedSub::checked_sub(self, other)
+    }
+
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        CheckedMul::checked_mul(self, other)
+    }
+
+    fn as_usize(&self) -> Option<usize> {
+        if self.is_integer() {
+            self.to_integer().to_usize()
+        } else {
+            None
+        }
+    }
+
+    fn as_f64(&self) -> f64 {
+        match (self.numer().to_f64(), self.denom().to_f64()) {
+            (Some(numerator), Some(denominator)) => numerator / denominator,
+            _ => f64::NAN,
+        }
+    }
 }
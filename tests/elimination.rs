@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+extern crate symbolic_polynomials;
+use symbolic_polynomials::*;
+
+#[allow(dead_code)]
+type TestPolynomial = Polynomial<String, i64, u8>;
+
+#[test]
+pub fn eliminate_linear_test() {
+    let a: TestPolynomial = variable("a".into());
+    let b: TestPolynomial = variable("b".into());
+    let c: TestPolynomial = variable("c".into());
+    // a = 2b, c = b + 3
+    let equations = vec![&a - 2 * &b, &c - &b - 3];
+    // a = 2c - 6
+    assert_eq!(eliminate(&equations, &["b".into()]), vec![&a - 2 * &c + 6]);
+    // b = c - 3
+    assert_eq!(eliminate(&equations, &["a".into()]), vec![&b - &c + 3]);
+    // Nothing is left after eliminating everything but a single variable
+    assert!(eliminate(&equations, &["a".into(), "b".into(), "c".into()]).is_empty());
+}
+
+#[test]
+pub fn eliminate_nonlinear_test() {
+    let t: TestPolynomial = variable("t".into());
+    let x: TestPolynomial = variable("x".into());
+    let y: TestPolynomial = variable("y".into());
+    // x = t^2, y = t^3
+    let equations = vec![&x - &t * &t, &y - &t * &t * &t];
+    // x^3 = y^2
+    let relations = eliminate(&equations, &["t".into()]);
+    assert_eq!(relations, vec![&x * &x * &x - &y * &y]);
+
+    // The relations hold for any values satisfying the original equations
+    let mut values = HashMap::<String, i64>::new();
+    for t_val in -3..4 {
+        values.insert("x".into(), t_val * t_val);
+        values.insert("y".into(), t_val * t_val * t_val);
+        for r in &relations {
+            assert_eq!(r.eval(&values), Ok(0));
+        }
+    }
+}
+
+#[test]
+pub fn eliminate_composite_test() {
+    let a: TestPolynomial = variable("a".into());
+    let b: TestPolynomial = variable("b".into());
+    let c: TestPolynomial = variable("c".into());
+    let d: TestPolynomial = variable("d".into());
+    let two = TestPolynomial::from(2);
+    // a = floor(b, 2) * d, c = floor(b, 2) + 1
+    let equations = vec![&a - floor(&b, &two) * &d, &c - floor(&b, &two) - 1];
+    // a = cd - d
+    assert_eq!(eliminate(&equations, &["b".into()]), vec![&a - &c * &d + &d]);
+}
+
+#[test]
+pub fn eliminate_inconsistent_test() {
+    let a: TestPolynomial = variable("a".into());
+    let b: TestPolynomial = variable("b".into());
+    // a = b, a = b + 1 has no solution
+    let equations = vec![&a - &b, &a - &b - 1];
+    assert_eq!(eliminate(&equations, &["b".into()]), vec![TestPolynomial::from(1)]);
+}
+
+#[test]
+pub fn eliminate_laurent_test() {
+    let a: Polynomial<String, i64, i8> = variable("a".into());
+    let b: Polynomial<String, i64, i8> = variable("b".into());
+    let n: Polynomial<String, i64, i8> = variable("n".into());
+    // n / a = b, a = b
+    let equations = vec![&n / &a - &b, &a - &b];
+    // n = b^2
+    assert_eq!(eliminate(&equations, &["a".into()]), vec![&b * &b - &n]);
+}
@@ -4,11 +4,16 @@ extern crate symbolic_polynomials;
 extern crate num;
 use symbolic_polynomials::*;
 use num::Integer;
+use num::rational::Ratio;
 
 #[allow(dead_code)]
 type TestMonomial = Monomial<String, i64, u8>;
 #[allow(dead_code)]
 type TestPolynomial = Polynomial<String, i64, u8>;
+#[allow(dead_code)]
+type RationalPolynomial = Polynomial<String, Ratio<i64>, u8>;
+#[allow(dead_code)]
+type LaurentPolynomial = Polynomial<String, i64, i8>;
 
 #[test]
 pub fn max_test() {
@@ -29,19 +34,19 @@ pub fn max_test() {
     assert_eq!(a_v2.monomials[0].coefficient, 1);
     assert_eq!(
         a_v2.monomials[0].powers,
-        vec![(Composite::Max(Rc::new(a_square.clone()), Rc::new(a)), 1)]
+        vec![(Composite::Max(vec![Rc::new(a_square.clone()), Rc::new(a)]), 1)]
     );
     assert_eq!(max_a_square_b.monomials.len(), 1);
     assert_eq!(max_a_square_b.monomials[0].coefficient, 1);
     assert_eq!(
         max_a_square_b.monomials[0].powers,
-        vec![(Composite::Max(Rc::new(a_square), Rc::new(b.clone())), 1)]
+        vec![(Composite::Max(vec![Rc::new(a_square), Rc::new(b.clone())]), 1)]
     );
     assert_eq!(max_a_third_b.monomials.len(), 1);
     assert_eq!(max_a_third_b.monomials[0].coefficient, 1);
     assert_eq!(
         max_a_third_b.monomials[0].powers,
-        vec![(Composite::Max(Rc::new(a_third), Rc::new(b)), 1)]
+        vec![(Composite::Max(vec![Rc::new(a_third), Rc::new(b)]), 1)]
     );
     let mut values = HashMap::<String, i64>::new();
     values.insert("a".into(), 3);
@@ -71,19 +76,19 @@ pub fn min_test() {
     assert_eq!(a_v2.monomials[0].coefficient, 1);
     assert_eq!(
         a_v2.monomials[0].powers,
-        vec![(Composite::Min(Rc::new(a_square.clone()), Rc::new(a)), 1)]
+        vec![(Composite::Min(vec![Rc::new(a_square.clone()), Rc::new(a)]), 1)]
     );
     assert_eq!(min_a_square_b.monomials.len(), 1);
     assert_eq!(min_a_square_b.monomials[0].coefficient, 1);
     assert_eq!(
         min_a_square_b.monomials[0].powers,
-        vec![(Composite::Min(Rc::new(a_square), Rc::new(b.clone())), 1)]
+        vec![(Composite::Min(vec![Rc::new(a_square), Rc::new(b.clone())]), 1)]
     );
     assert_eq!(min_a_third_b.monomials.len(), 1);
     assert_eq!(min_a_third_b.monomials[0].coefficient, 1);
     assert_eq!(
         min_a_third_b.monomials[0].powers,
-        vec![(Composite::Min(Rc::new(a_third), Rc::new(b)), 1)]
+        vec![(Composite::Min(vec![Rc::new(a_third), Rc::new(b)]), 1)]
     );
 
     let mut values = HashMap::<String, i64>::new();
@@ -95,6 +100,101 @@ pub fn min_test() {
     assert_eq!(min_a_third_b.eval(&values), Ok(13));
 }
 
+#[test]
+pub fn min_max_simplification_test() {
+    let a: TestPolynomial = variable("a".into());
+    let b: TestPolynomial = variable("b".into());
+    let c: TestPolynomial = variable("c".into());
+    let max_a_b = max(&a, &b);
+    let min_a_b = min(&a, &b);
+
+    assert_eq!(max(&a, &a + 3), &a + 3);
+    assert_eq!(min(&a, &a + 3), a);
+    assert_eq!(max(&a, &a), a);
+    assert_eq!(min(&a, &a), a);
+    assert_eq!(max(&a * &a + &b, &b), &a * &a + &b);
+    assert_eq!(max(&a, &min_a_b), a);
+    assert_eq!(min(&a, &max_a_b), a);
+    assert_eq!(max(&max_a_b, &a), max_a_b);
+    assert_eq!(max(&max_a_b, &b + 1), max(&a, &b + 1));
+    assert_eq!(max(&a + &c, &b + &c), &max_a_b + &c);
+    assert_eq!(min(&a * &c + 2, &b * &c + 2), min(&a * &c, &b * &c) + 2);
+    assert_eq!(max(&max_a_b + 1, &c + 1), max(&max_a_b, &c) + 1);
+    assert_eq!(
+        max(&max_a_b, &c).monomials[0].powers,
+        vec![
+            (
+                Composite::Max(vec![Rc::new(a.clone()), Rc::new(b.clone()), Rc::new(c.clone())]),
+                1,
+            ),
+        ]
+    );
+
+    let nested = min(max(&a, &b) + &c, max(&a + &c, &b));
+    let mut values = HashMap::<String, i64>::new();
+    for a_val in -3..4 {
+        for b_val in -3..4 {
+            for c_val in -3..4 {
+                values.insert("a".into(), a_val);
+                values.insert("b".into(), b_val);
+                values.insert("c".into(), c_val);
+                let expected = ::std::cmp::min(
+                    ::std::cmp::max(a_val, b_val) + c_val,
+                    ::std::cmp::max(a_val + c_val, b_val),
+                );
+                assert_eq!(nested.eval(&values), Ok(expected));
+            }
+        }
+    }
+}
+
+#[test]
+pub fn commutative_order_test() {
+    let a: TestPolynomial = variable("a".into());
+    let b: TestPolynomial = variable("b".into());
+    let c: TestPolynomial = variable("c".into());
+    let a_square = &a * &a;
+
+    assert_eq!(max(&a, &b), max(&b, &a));
+    assert_eq!(min(&a_square, &b), min(&b, &a_square));
+    assert_eq!(gcd(&a, &b), gcd(&b, &a));
+    assert_eq!(lcm(&a_square, &b), lcm(&b, &a_square));
+    assert_eq!(max(&a, &b) + max(&b, &a), max(&a, &b) * 2);
+    assert_eq!(min(&a, &b) - min(&b, &a), TestPolynomial::default());
+    assert_eq!(max(max(&a, &b), &c), max(&c, max(&b, &a)));
+    assert_eq!(min(min(&a, &b), &c), min(&a, min(&c, &b)));
+    assert_eq!(gcd(gcd(&a, &b), &c), gcd(&c, gcd(&b, &a)));
+    assert_eq!(lcm(lcm(&a, &b), &c), lcm(&b, lcm(&a, &c)));
+    assert_eq!(
+        min(min(&a, &b), &c).monomials[0].powers,
+        vec![
+            (
+                Composite::Min(vec![Rc::new(a.clone()), Rc::new(b.clone()), Rc::new(c.clone())]),
+                1,
+            ),
+        ]
+    );
+    assert_eq!(
+        gcd(&c, gcd(&b, &a)).monomials[0].powers,
+        vec![
+            (
+                Composite::Gcd(vec![Rc::new(a.clone()), Rc::new(b.clone()), Rc::new(c.clone())]),
+                1,
+            ),
+        ]
+    );
+    assert_eq!(format!("{}", lcm(&c, lcm(&b, &a))), "lcm(a, b, c)");
+    assert_eq!(gcd(gcd(&a, &b), &a * &c), gcd(&a, &b));
+    assert_eq!(lcm(lcm(&a, &b), &a * &b), abs(&a * &b));
+
+    let polynomial = max(&c, max(&b, &a)) + gcd(&c, gcd(&b, &a)) + lcm(&a, lcm(&c, &b));
+    let mut values = HashMap::<String, i64>::new();
+    values.insert("a".into(), 4);
+    values.insert("b".into(), -6);
+    values.insert("c".into(), 10);
+    assert_eq!(polynomial.eval(&values), Ok(10 + 2 + 60));
+}
+
 #[test]
 pub fn ceil_test() {
     let thirteen = TestPolynomial::from(13);
@@ -131,6 +231,13 @@ pub fn ceil_test() {
     assert_eq!(five.eval(&values), Ok(5));
     assert_eq!(ceil_a_square_b.eval(&values), Ok(1));
     assert_eq!(ceil_a_third_b.eval(&values), Ok(3));
+
+    // Negative quotients round towards positive infinity
+    assert_eq!(ceil(TestPolynomial::from(-7), TestPolynomial::from(2)), -3);
+    assert_eq!(ceil(TestPolynomial::from(7), TestPolynomial::from(-2)), -3);
+    assert_eq!(ceil(TestPolynomial::from(-8), TestPolynomial::from(2)), -4);
+    values.insert("a".into(), -3);
+    assert_eq!(ceil_a_third_b.eval(&values), Ok(-2));
 }
 
 #[test]
@@ -172,6 +279,636 @@ pub fn floor_test() {
     assert_eq!(floor_a_third_b.eval(&values), Ok(2));
 }
 
+#[test]
+pub fn floor_rewrites_test() {
+    let a: TestPolynomial = variable("a".into());
+    let b: TestPolynomial = variable("b".into());
+    let four = TestPolynomial::from(4);
+    let three = TestPolynomial::from(3);
+
+    assert_eq!(floor(4 * &a + 3, &four), a);
+    assert_eq!(floor(4 * &a - 1, &four), &a - 1);
+    assert_eq!(floor(6 * &a + 5, &four), &a + 1 + floor(2 * &a + 1, &four));
+    assert_eq!(floor(2 * &a, &four), floor(&a, TestPolynomial::from(2)));
+    assert_eq!(floor(&a * &b + &a + 2 * &b, &b), &a + 2 + floor(&a, &b));
+    assert_eq!(floor(floor(&a, &three), &four), floor(&a, TestPolynomial::from(12)));
+    assert_eq!(floor(floor(&a, &three) + 1, &four), floor(&a + 3, TestPolynomial::from(12)));
+    assert_eq!(
+        floor(floor(&a, exp2(&b)), TestPolynomial::from(2)),
+        floor(&a, exp2(&b + 1))
+    );
+    assert_eq!(ceil(&a, &four), floor(&a + 3, &four));
+    assert_eq!(ceil(4 * &a + 1, &four), &a + 1);
+    assert_eq!(ceil(ceil(&a, &three), &four), ceil(&a, TestPolynomial::from(12)));
+    assert_eq!(ceil(&a * &b + 1, &b), &a + ceil(TestPolynomial::from(1), &b));
+
+    // Pooling layers with kernel 2, stride 2 and padding 1
+    let pool = |x: &TestPolynomial| floor(x + 2 - 2, TestPolynomial::from(2)) + 1;
+    assert_eq!(pool(&pool(&(4 * &a))), &a + 1);
+
+    let ceil_div = |x: i64, y: i64| -(-x).div_floor(&y);
+    let expressions: Vec<(TestPolynomial, Box<dyn Fn(i64, i64) -> i64>)> = vec![
+        (floor(4 * &a + 3, &four), Box::new(|a, _| (4 * a + 3).div_floor(&4))),
+        (floor(6 * &a + 5, &four), Box::new(|a, _| (6 * a + 5).div_floor(&4))),
+        (
+            floor(floor(&a, &three) + &b, &four),
+            Box::new(|a, b| (a.div_floor(&3) + b).div_floor(&4)),
+        ),
+        (
+            ceil(ceil(&a, &three) - &b, &four),
+            Box::new(move |a, b| ceil_div(ceil_div(a, 3) - b, 4)),
+        ),
+    ];
+    let mut values = HashMap::<String, i64>::new();
+    for a_val in -20..21 {
+        for b_val in -3..4 {
+            values.insert("a".into(), a_val);
+            values.insert("b".into(), b_val);
+            for (p, f) in &expressions {
+                assert_eq!(p.eval(&values), Ok(f(a_val, b_val)));
+            }
+        }
+    }
+}
+
+#[test]
+pub fn assumptions_test() {
+    let a: TestPolynomial = variable("a".into());
+    let b: TestPolynomial = variable("b".into());
+    let c: TestPolynomial = variable("c".into());
+    let zero = TestPolynomial::default();
+    let eight = TestPolynomial::from(8);
+    let mut assumptions = Assumptions::new();
+    assumptions.assume_positive("a".into());
+    assumptions.assume_multiple_of("a".into(), 8);
+    assumptions.assume_at_least("b".into(), 2);
+    assumptions.assume_at_most("c".into(), 10);
+
+    assert_eq!(assumptions.lower_bound(&"a".into()), Some(&1));
+    assert_eq!(assumptions.upper_bound(&"a".into()), None);
+    assert_eq!(assumptions.divisor(&"a".into()), 8);
+    assert_eq!(assumptions.divisor(&"b".into()), 1);
+
+    assert_ne!(max(&a, &zero), a);
+    assert_eq!(max_with(&a, &zero, &assumptions), a);
+    assert_eq!(min_with(&a, &zero, &assumptions), zero);
+    assert_eq!(max_with(&a, &eight, &assumptions), a);
+    assert_eq!(max_with(&a * &b, &a, &assumptions), &a * &b);
+    assert_eq!(min_with(&b - 1, TestPolynomial::from(1), &assumptions), 1);
+    assert_eq!(min_with(&c, TestPolynomial::from(10), &assumptions), c);
+    assert_eq!(max_with(&c, TestPolynomial::from(12), &assumptions), 12);
+
+    assert_eq!(floor_with(&a + 3, &eight, &assumptions), floor(&a, &eight));
+    assert_eq!(ceil_with(&a, &eight, &assumptions), floor(&a, &eight));
+    assert_eq!(ceil_with(&a + 3, &eight, &assumptions), floor(&a, &eight) + 1);
+    assert_eq!(
+        floor_with(&a + &b, &eight, &assumptions),
+        floor(&a, &eight) + floor(&b, &eight)
+    );
+    assert_eq!(reduce_with(floor(&a, &eight) * 8, &HashMap::new(), &assumptions), a);
+    assert_eq!(reduce_with(ceil(&a, &eight) * 16 + 1, &HashMap::new(), &assumptions), 2 * &a + 1);
+    assert_eq!(reduce_with(max(&a, &zero) + &b, &HashMap::new(), &assumptions), &a + &b);
+    assert_eq!(
+        reduce_with(floor(&a, &eight) * 8 + &c, &HashMap::new(), &Assumptions::new()),
+        floor(&a, &eight) * 8 + &c
+    );
+
+    let mut values = HashMap::<String, i64>::new();
+    values.insert("b".into(), 3);
+    assert_eq!(reduce_with(floor(&a * &b, &eight) * 8, &values, &assumptions), 3 * &a);
+    values.insert("a".into(), 16);
+    assert_eq!(floor_with(&a + 3, &eight, &assumptions).eval(&values), Ok(2));
+
+    // Only the magnitude of a divisor matters and a multiple of zero is zero
+    let mut divisors = Assumptions::new();
+    divisors.assume_multiple_of("a".into(), -8);
+    assert_eq!(divisors.divisor(&"a".into()), 8);
+    divisors.assume_multiple_of("b".into(), 0);
+    assert_eq!(divisors.divisor(&"b".into()), 1);
+    assert_eq!(divisors.lower_bound(&"b".into()), Some(&0));
+    assert_eq!(divisors.upper_bound(&"b".into()), Some(&0));
+    assert_eq!(max_with(&b, &zero, &divisors), b);
+    divisors.assume_at_least("b".into(), 1);
+    max_with(&b, &zero, &divisors);
+    prove_ge(&b, &zero, &divisors);
+}
+
+#[test]
+pub fn prove_ge_test() {
+    let a: TestPolynomial = variable("a".into());
+    let b: TestPolynomial = variable("b".into());
+    let c: TestPolynomial = variable("c".into());
+    let none = Assumptions::new();
+    let mut positive = Assumptions::new();
+    positive.assume_positive("a".into());
+    positive.assume_positive("b".into());
+
+    assert_eq!(prove_ge(&a * &b + &a, &a * &b, &positive), Proof::Proven);
+    assert_eq!(prove_ge(&a * &b, &a, &positive), Proof::Proven);
+    assert_eq!(prove_ge(&a * &a + 1, TestPolynomial::from(1), &none), Proof::Proven);
+    assert_eq!(prove_ge(max(&a, &b), &a, &none), Proof::Proven);
+    assert_eq!(prove_ge(max(&a, &b), min(&a, &c), &none), Proof::Proven);
+    assert_eq!(prove_ge(abs(&a - &b) + &b, &a, &none), Proof::Proven);
+    assert_eq!(
+        prove_ge(select(Condition::new(&a, Relation::Greater, &b), &a, &b), &b, &none),
+        Proof::Proven
+    );
+    assert!(!prove_ge(&a * &b + &a, &a * &b, &none).is_proven());
+
+    let mut bounded = Assumptions::new();
+    bounded.assume_positive("a".into());
+    bounded.assume_at_most("a".into(), 256);
+    bounded.assume_positive("b".into());
+    bounded.assume_at_most("b".into(), 4096);
+    assert_eq!(
+        prove_ge(TestPolynomial::from(256 * 4096), &a * &b, &bounded),
+        Proof::Proven
+    );
+
+    let disproven = prove_ge(&a, &b, &positive);
+    let counterexample = disproven.counterexample().unwrap();
+    assert!(a.eval(counterexample).unwrap() < b.eval(counterexample).unwrap());
+    let disproven = prove_ge(TestPolynomial::from(1000), &a * &b, &bounded);
+    let counterexample = disproven.counterexample().unwrap();
+    assert!(counterexample["a"] <= 256 && counterexample["b"] <= 4096);
+    assert!((&a * &b).eval(counterexample).unwrap() > 1000);
+    let mut multiple = Assumptions::new();
+    multiple.assume_multiple_of("a".into(), 8);
+    let disproven = prove_ge(&a, TestPolynomial::from(3), &multiple);
+    assert_eq!(disproven.counterexample().unwrap()["a"] % 8, 0);
+
+    // (a - 1)^2 >= 0 holds, hence it is never disproven
+    assert!(prove_ge(&a * &a + 1, 2 * &a, &none).counterexample().is_none());
+}
+
+#[test]
+pub fn equivalent_test() {
+    let a: TestPolynomial = variable("a".into());
+    let b: TestPolynomial = variable("b".into());
+    let two = TestPolynomial::from(2);
+    let eight = TestPolynomial::from(8);
+
+    assert_eq!(equivalent(min(&a, &b) + max(&a, &b), &a + &b), Proof::Proven);
+    assert_eq!(equivalent((&a + &b) * (&a + &b), &a * &a + 2 * &a * &b + &b * &b),
+               Proof::Proven);
+    assert_eq!(equivalent(abs(&a - &b), max(&a, &b) - min(&a, &b)), Proof::Proven);
+    let disproven = equivalent(&a * &a, &a);
+    let counterexample = disproven.counterexample().unwrap();
+    assert!((&a * &a).eval(counterexample).unwrap() != a.eval(counterexample).unwrap());
+    assert!(equivalent(floor(&a, &eight) * 8, &a).counterexample().is_some());
+
+    let mut multiple = Assumptions::new();
+    multiple.assume_multiple_of("a".into(), 8);
+    assert_eq!(equivalent_with(floor(&a, &eight) * 8, &a, &multiple), Proof::Proven);
+
+    // a * (a + 1) is always even, hence it is never disproven
+    let even = &a * &a + &a;
+    assert!(equivalent(floor(&even, &two) * 2, &even).counterexample().is_none());
+
+    // Sampling close to the limits of the type does not overflow
+    let mut huge = Assumptions::new();
+    huge.assume_at_least("a".into(), i64::MAX - 8);
+    let half = floor(&a, &two);
+    assert!(equivalent_with(&half + &half + modulo(&a, &two), &a, &huge)
+        .counterexample()
+        .is_none());
+}
+
+#[test]
+pub fn modulo_test() {
+    let thirteen = TestPolynomial::from(13);
+    let three = TestPolynomial::from(3);
+    let one = modulo(&thirteen, &three);
+    let two = modulo(-&thirteen, &three);
+    let a: TestPolynomial = variable("a".into());
+    let b: TestPolynomial = variable("b".into());
+    let a_square = &a * &a;
+    let a_third = &a_square * &a;
+    let zero = modulo(&a_square, &a);
+    let mod_a_square_b = modulo(&a_square, &b);
+    let mod_a_third_b = modulo(&a_third, &b);
+
+    assert_eq!(one, 1);
+    assert_eq!(two, 2);
+    assert_eq!(zero, TestPolynomial::default());
+    assert_eq!(mod_a_square_b.monomials.len(), 1);
+    assert_eq!(mod_a_square_b.monomials[0].coefficient, 1);
+    assert_eq!(
+        mod_a_square_b.monomials[0].powers,
+        vec![(Composite::Mod(Rc::new(a_square.clone()), Rc::new(b.clone())), 1)]
+    );
+    assert_eq!(format!("{}", mod_a_third_b), "mod(a^3, b)");
+    assert_eq!(mod_a_third_b.to_code(&|x: String| x), "mod(a * a * a, b)");
+
+    let mut values = HashMap::<String, i64>::new();
+    values.insert("a".into(), 3);
+    values.insert("b".into(), 13);
+
+    assert_eq!(mod_a_square_b.eval(&values), Ok(9));
+    assert_eq!(mod_a_third_b.eval(&values), Ok(1));
+    values.insert("a".into(), -3);
+    assert_eq!(mod_a_third_b.eval(&values), Ok(12));
+    values.insert("b".into(), -13);
+    assert_eq!(mod_a_third_b.eval(&values), Ok(-1));
+    values.insert("b".into(), 0);
+    assert_eq!(
+        mod_a_third_b.eval(&values),
+        Err(("".into(), "Attempting division by zero.".into()))
+    );
+
+    // mod(a^2, b)^2 with b = 5
+    values.clear();
+    values.insert("b".into(), 5);
+    let squared = &mod_a_square_b * &mod_a_square_b;
+    let mod_a_square_five = modulo(&a_square, TestPolynomial::from(5));
+    assert_eq!(reduce(&squared, &values), &mod_a_square_five * &mod_a_square_five);
+    values.insert("a".into(), 3);
+    assert_eq!(reduce(&squared, &values), 16);
+}
+
+#[test]
+pub fn trunc_div_test() {
+    let a: TestPolynomial = variable("a".into());
+    let b: TestPolynomial = variable("b".into());
+    let seven = TestPolynomial::from(7);
+    let two = TestPolynomial::from(2);
+    let trunc_a_b = trunc_div(&a, &b);
+
+    assert_eq!(trunc_div(&seven, &two), 3);
+    assert_eq!(trunc_div(-&seven, &two), -3);
+    assert_eq!(trunc_div(&seven, -&two), -3);
+    assert_eq!(trunc_div(&a * &b + &b, &b), &a + 1);
+    assert_eq!(
+        trunc_a_b.monomials[0].powers,
+        vec![(Composite::TruncDiv(Rc::new(a.clone()), Rc::new(b.clone())), 1)]
+    );
+    assert_eq!(format!("{}", trunc_a_b), "trunc_div(a, b)");
+    assert_eq!(trunc_a_b.to_code(&|x: String| x), "trunc_div(a, b)");
+
+    let mut values = HashMap::<String, i64>::new();
+    values.insert("a".into(), -7);
+    values.insert("b".into(), 2);
+    assert_eq!(trunc_a_b.eval(&values), Ok(-3));
+    assert_eq!(floor(&a, &b).eval(&values), Ok(-4));
+    assert_eq!(ceil(&a, &b).eval(&values), Ok(-3));
+    values.insert("b".into(), 0);
+    assert_eq!(trunc_a_b.eval(&values), Err(("".into(), "Attempting division by zero.".into())));
+}
+
+#[test]
+pub fn round_div_test() {
+    let a: TestPolynomial = variable("a".into());
+    let b: TestPolynomial = variable("b".into());
+    let round_a_b = round_div(&a, &b);
+
+    assert_eq!(round_div(TestPolynomial::from(7), TestPolynomial::from(2)), 4);
+    assert_eq!(round_div(TestPolynomial::from(-7), TestPolynomial::from(2)), -4);
+    assert_eq!(round_div(TestPolynomial::from(7), TestPolynomial::from(-2)), -4);
+    assert_eq!(round_div(TestPolynomial::from(-7), TestPolynomial::from(-2)), 4);
+    assert_eq!(round_div(TestPolynomial::from(5), TestPolynomial::from(3)), 2);
+    assert_eq!(round_div(TestPolynomial::from(4), TestPolynomial::from(3)), 1);
+    assert_eq!(round_div(TestPolynomial::from(-4), TestPolynomial::from(3)), -1);
+    assert_eq!(round_div(TestPolynomial::from(-5), TestPolynomial::from(3)), -2);
+    assert_eq!(round_div(2 * &a * &b, &b), 2 * &a);
+    assert_eq!(
+        round_a_b.monomials[0].powers,
+        vec![(Composite::RoundDiv(Rc::new(a.clone()), Rc::new(b.clone())), 1)]
+    );
+    assert_eq!(format!("{}", round_a_b), "round_div(a, b)");
+
+    let mut values = HashMap::<String, i64>::new();
+    for a_val in -12..13 {
+        for b_val in (-5..6).filter(|&x| x != 0) {
+            values.insert("a".into(), a_val);
+            values.insert("b".into(), b_val);
+            let expected = (a_val as f64 / b_val as f64).round() as i64;
+            assert_eq!(round_a_b.eval(&values), Ok(expected));
+        }
+    }
+}
+
+#[test]
+pub fn abs_test() {
+    let a: TestPolynomial = variable("a".into());
+    let b: TestPolynomial = variable("b".into());
+    let a_minus_b = &a - &b;
+    let abs_a_b = abs(&a_minus_b);
+
+    assert_eq!(abs(TestPolynomial::from(-7)), 7);
+    assert_eq!(abs(TestPolynomial::from(7)), 7);
+    assert_eq!(abs(&a * &a + 1), &a * &a + 1);
+    assert_eq!(abs(-(&a * &a) - &b * &b), &a * &a + &b * &b);
+    assert_eq!(abs(&b - &a), abs_a_b);
+    assert_eq!(abs(6 * &b - 6 * &a), 6 * &abs_a_b);
+    assert_eq!(abs(&abs_a_b), abs_a_b);
+    assert_eq!(
+        abs_a_b.monomials[0].powers,
+        vec![(Composite::Abs(Rc::new(a_minus_b.clone())), 1)]
+    );
+    assert_eq!(format!("{}", abs_a_b), "abs(a - b)");
+    assert_eq!(abs_a_b.to_code(&|x| x), "abs(a - b)");
+
+    let mut values = HashMap::<String, i64>::new();
+    values.insert("a".into(), 3);
+    values.insert("b".into(), 5);
+    assert_eq!(abs_a_b.eval(&values), Ok(2));
+    assert_eq!(reduce(&abs_a_b, &values), 2);
+}
+
+#[test]
+pub fn sign_test() {
+    let a: TestPolynomial = variable("a".into());
+    let b: TestPolynomial = variable("b".into());
+    let a_minus_b = &a - &b;
+    let sign_a_b = sign(&a_minus_b);
+
+    assert_eq!(sign(TestPolynomial::from(-7)), -1);
+    assert_eq!(sign(TestPolynomial::from(0)), 0);
+    assert_eq!(sign(TestPolynomial::from(7)), 1);
+    assert_eq!(sign(&a * &a + 1), 1);
+    assert_eq!(sign(-(&a * &a) - 1), -1);
+    assert_eq!(sign(&b - &a), -&sign_a_b);
+    assert_eq!(sign(6 * &a - 6 * &b), sign_a_b);
+    assert_eq!(
+        sign_a_b.monomials[0].powers,
+        vec![(Composite::Sign(Rc::new(a_minus_b.clone())), 1)]
+    );
+    assert_eq!(format!("{}", sign_a_b), "sign(a - b)");
+
+    let mut values = HashMap::<String, i64>::new();
+    for a_val in -3..4 {
+        for b_val in -3..4 {
+            values.insert("a".into(), a_val);
+            values.insert("b".into(), b_val);
+            assert_eq!(sign_a_b.eval(&values), Ok((a_val - b_val).signum()));
+            assert_eq!(
+                reduce(&sign_a_b, &values).eval(&HashMap::new()),
+                Ok((a_val - b_val).signum())
+            );
+        }
+    }
+}
+
+#[test]
+pub fn gcd_test() {
+    let a: TestPolynomial = variable("a".into());
+    let b: TestPolynomial = variable("b".into());
+    let gcd_a_b = gcd(&a, &b);
+
+    assert_eq!(gcd(TestPolynomial::from(12), TestPolynomial::from(-18)), 6);
+    assert_eq!(gcd(&a * &a + 1, TestPolynomial::default()), &a * &a + 1);
+    assert_eq!(gcd(&a, &a * &b), abs(&a));
+    assert_eq!(gcd(&a * &a * &b, -&a * &b), abs(&a * &b));
+    assert_eq!(gcd(&a, TestPolynomial::from(1)), 1);
+    assert_eq!(gcd(6 * &a, -6 * &b), 6 * &gcd_a_b);
+    assert_eq!(gcd(6 * &a * &a, 6 * &a * &b), 6 * abs(&a) * &gcd_a_b);
+    assert_eq!(gcd(-&a, &b), gcd_a_b);
+    assert_eq!(
+        gcd_a_b.monomials[0].powers,
+        vec![(Composite::Gcd(vec![Rc::new(a.clone()), Rc::new(b.clone())]), 1)]
+    );
+    assert_eq!(format!("{}", gcd_a_b), "gcd(a, b)");
+    assert_eq!(abs(&gcd_a_b), gcd_a_b);
+
+    let mut values = HashMap::<String, i64>::new();
+    values.insert("a".into(), 12);
+    values.insert("b".into(), -18);
+    assert_eq!(gcd_a_b.eval(&values), Ok(6));
+    assert_eq!(reduce(&gcd_a_b, &values), 6);
+
+    // With rational coefficients a / 2 is not a multiple of 1
+    let x: RationalPolynomial = variable("a".into());
+    let two = RationalPolynomial::from(Ratio::from_integer(2));
+    let mut rational_values = HashMap::new();
+    rational_values.insert("a".into(), Ratio::from_integer(3));
+    assert_eq!(gcd(&x, &two).eval(&rational_values), Ok(Ratio::from_integer(1)));
+    assert_eq!(gcd(&x * &two, &two).eval(&rational_values), Ok(Ratio::from_integer(2)));
+
+    // n / a does not divide n
+    let n: LaurentPolynomial = variable("n".into());
+    let a: LaurentPolynomial = variable("a".into());
+    let mut laurent_values = HashMap::new();
+    laurent_values.insert("n".into(), 12);
+    laurent_values.insert("a".into(), 4);
+    assert_eq!(gcd(&(&n / &a), &n).eval(&laurent_values), Ok(3));
+}
+
+#[test]
+pub fn lcm_test() {
+    let a: TestPolynomial = variable("a".into());
+    let b: TestPolynomial = variable("b".into());
+    let lcm_a_b = lcm(&a, &b);
+
+    assert_eq!(lcm(TestPolynomial::from(4), TestPolynomial::from(-6)), 12);
+    assert_eq!(lcm(TestPolynomial::from(0), TestPolynomial::from(6)), 0);
+    assert_eq!(lcm(&a, TestPolynomial::default()), TestPolynomial::default());
+    assert_eq!(lcm(&a, &a * &b), abs(&a * &b));
+    assert_eq!(lcm(6 * &a, 6 * &b), 6 * &lcm_a_b);
+    assert_eq!(
+        lcm_a_b.monomials[0].powers,
+        vec![(Composite::Lcm(vec![Rc::new(a.clone()), Rc::new(b.clone())]), 1)]
+    );
+    assert_eq!(format!("{}", lcm_a_b), "lcm(a, b)");
+
+    let mut values = HashMap::<String, i64>::new();
+    values.insert("a".into(), 4);
+    values.insert("b".into(), -6);
+    assert_eq!(lcm_a_b.eval(&values), Ok(12));
+    assert_eq!(reduce(&lcm_a_b, &values), 12);
+
+    let x: RationalPolynomial = variable("a".into());
+    let two = RationalPolynomial::from(Ratio::from_integer(2));
+    let mut rational_values = HashMap::new();
+    rational_values.insert("a".into(), Ratio::from_integer(3));
+    assert_eq!(lcm(&x, &two).eval(&rational_values), Ok(Ratio::from_integer(6)));
+    assert_eq!(lcm(&x * &two, &two).eval(&rational_values), Ok(Ratio::from_integer(6)));
+
+    let n: LaurentPolynomial = variable("n".into());
+    let a: LaurentPolynomial = variable("a".into());
+    let mut laurent_values = HashMap::new();
+    laurent_values.insert("n".into(), 12);
+    laurent_values.insert("a".into(), 4);
+    assert_eq!(lcm(&(&n / &a), &n).eval(&laurent_values), Ok(12));
+}
+
+#[test]
+pub fn exp2_test() {
+    let a: TestPolynomial = variable("a".into());
+    let b: TestPolynomial = variable("b".into());
+    let exp2_a = exp2(&a);
+    let exp2_b = exp2(&b);
+
+    assert_eq!(exp2(TestPolynomial::from(5)), 32);
+    assert_eq!(exp2(TestPolynomial::default()), 1);
+    assert_eq!(exp2(&a + 3), 8 * &exp2_a);
+    assert_eq!(&exp2_a * &exp2_b, exp2(&a + &b));
+    assert_eq!(&exp2_a * &exp2_a * &exp2_a, exp2(3 * &a));
+    assert_eq!(exp2(&a - &b) * &exp2_b, exp2_a);
+    assert_eq!((&exp2_a + &b) * exp2(&a + 1), 2 * exp2(2 * &a) + 2 * &b * &exp2_a);
+    assert_eq!(sign(&exp2_a), 1);
+    assert_eq!(
+        exp2_a.monomials[0].powers,
+        vec![(Composite::Exp2(Rc::new(a.clone())), 1)]
+    );
+    assert_eq!(format!("{}", exp2(&a + &b)), "exp2(a + b)");
+    assert_eq!(exp2(&a + &b).to_code(&|x| x), "exp2(a + b)");
+
+    let mut values = HashMap::<String, i64>::new();
+    values.insert("a".into(), 3);
+    values.insert("b".into(), 4);
+    assert_eq!(exp2(&a + &b).eval(&values), Ok(128));
+    assert_eq!(reduce(&exp2_a, &values), 8);
+    values.insert("a".into(), -1);
+    assert!(exp2_a.eval(&values).is_err());
+}
+
+#[test]
+pub fn log2_test() {
+    let a: TestPolynomial = variable("a".into());
+    let b: TestPolynomial = variable("b".into());
+    let log2_floor_a = log2_floor(&a);
+    let log2_ceil_a = log2_ceil(&a);
+
+    assert_eq!(log2_floor(TestPolynomial::from(1)), 0);
+    assert_eq!(log2_floor(TestPolynomial::from(15)), 3);
+    assert_eq!(log2_floor(TestPolynomial::from(16)), 4);
+    assert_eq!(log2_ceil(TestPolynomial::from(1)), 0);
+    assert_eq!(log2_ceil(TestPolynomial::from(15)), 4);
+    assert_eq!(log2_ceil(TestPolynomial::from(16)), 4);
+    assert_eq!(log2_floor(exp2(&a)), a);
+    assert_eq!(log2_ceil(exp2(&a)), a);
+    assert_eq!(log2_floor(3 * exp2(&a) * exp2(&b)), &a + &b + 1);
+    assert_eq!(log2_ceil(3 * exp2(&a) * exp2(&b)), &a + &b + 2);
+    assert_eq!(
+        log2_floor_a.monomials[0].powers,
+        vec![(Composite::Log2Floor(Rc::new(a.clone())), 1)]
+    );
+    assert_eq!(format!("{}", log2_floor_a), "log2_floor(a)");
+    assert_eq!(format!("{}", log2_ceil_a), "log2_ceil(a)");
+    assert_eq!(log2_ceil_a.to_code(&|x| x), "log2_ceil(a)");
+
+    let mut values = HashMap::<String, i64>::new();
+    for a_val in 1..70 {
+        values.insert("a".into(), a_val);
+        let expected = (a_val as f64).log2();
+        assert_eq!(log2_floor_a.eval(&values), Ok(expected.floor() as i64));
+        assert_eq!(log2_ceil_a.eval(&values), Ok(expected.ceil() as i64));
+    }
+    values.insert("a".into(), 0);
+    assert!(log2_floor_a.eval(&values).is_err());
+    assert!(log2_ceil_a.eval(&values).is_err());
+}
+
+#[test]
+pub fn select_test() {
+    let a: TestPolynomial = variable("a".into());
+    let b: TestPolynomial = variable("b".into());
+    let c: TestPolynomial = variable("c".into());
+    let zero = TestPolynomial::default();
+    let three = TestPolynomial::from(3);
+    let a_ge_b = Condition::new(&a, Relation::GreaterOrEqual, &b);
+    let select_a_b = select(a_ge_b.clone(), &a, &b);
+    let c_gt_64 = Condition::new(&c, Relation::Greater, TestPolynomial::from(64));
+    let pad = select(c_gt_64, TestPolynomial::from(8), &zero);
+
+    assert_eq!(select(Condition::new(&a, Relation::Less, &a + 1), &b, &c), b);
+    assert_eq!(select(Condition::new(&a * &a + 1, Relation::Equal, &zero), &b, &c), c);
+    assert_eq!(select(Condition::new(&a * &a, Relation::GreaterOrEqual, &zero), &b, &c), b);
+    assert_eq!(select(Condition::new(&a * &a, Relation::Less, &zero), &b, &c), c);
+    assert_eq!(select(a_ge_b.clone(), &c, &c), c);
+    assert_eq!(
+        select_a_b.monomials[0].powers,
+        vec![
+            (
+                Composite::Select(Rc::new(a_ge_b.clone()), Rc::new(a.clone()), Rc::new(b.clone())),
+                1,
+            ),
+        ]
+    );
+    assert_eq!(format!("{}", select_a_b), "select(a >= b, a, b)");
+    assert_eq!(format!("{}", pad), "select(c > 64, 8, 0)");
+    assert_eq!(pad.to_code(&|x| x), "select(c > 64, 8, 0)");
+
+    let mut unique = HashSet::new();
+    select(Condition::new(&c, Relation::NotEqual, &zero), &a, &b).unique_identifiers(&mut unique);
+    assert_eq!(unique, vec!["a".to_string(), "b".into(), "c".into()].into_iter().collect());
+
+    let mut values = HashMap::<String, i64>::new();
+    values.insert("c".into(), 128);
+    assert_eq!(pad.eval(&values), Ok(8));
+    assert_eq!(reduce(&pad, &values), 8);
+    values.insert("c".into(), 64);
+    assert_eq!(pad.eval(&values), Ok(0));
+    values.insert("a".into(), 3);
+    let three_ge_b = Condition::new(&three, Relation::GreaterOrEqual, &b);
+    assert_eq!(reduce(&select_a_b, &values), select(three_ge_b, &three, &b));
+    values.insert("b".into(), 3);
+    assert_eq!(reduce(&select_a_b, &values), 3);
+    values.insert("b".into(), 5);
+    assert_eq!(select_a_b.eval(&values), Ok(5));
+
+    values.remove("a");
+    values.remove("b");
+    let shifted = select(Condition::new(&a, Relation::Greater, &b), &a - &b + &c, &c);
+    values.insert("c".into(), 10);
+    let a_gt_b = Condition::new(&a, Relation::Greater, &b);
+    assert_eq!(
+        reduce(&shifted, &values),
+        select(a_gt_b, &a - &b + 10, TestPolynomial::from(10))
+    );
+}
+
+#[test]
+pub fn apply_test() {
+    let a: TestPolynomial = variable("a".into());
+    let b: TestPolynomial = variable("b".into());
+    let conv = apply(FnId::from("conv"), &[&a + 1, b.clone()]);
+
+    assert_eq!(conv, apply(FnId::from("conv"), &[1 + &a, b.clone()]));
+    assert!(conv != apply(FnId::from("conv"), &[b.clone(), &a + 1]));
+    assert!(conv != apply(FnId::from("pool"), &[&a + 1, b.clone()]));
+    assert_eq!(&conv + &conv, 2 * &conv);
+    assert_eq!(
+        conv.monomials[0].powers,
+        vec![(Composite::Apply(FnId::from("conv"), vec![Rc::new(&a + 1), Rc::new(b.clone())]), 1)]
+    );
+    assert_eq!(format!("{}", conv), "conv(a + 1, b)");
+    assert_eq!(conv.to_code(&|x| x), "conv(a + 1, b)");
+
+    let mut unique = HashSet::new();
+    conv.unique_identifiers(&mut unique);
+    assert_eq!(unique, vec!["a".to_string(), "b".into()].into_iter().collect());
+
+    let mut values = HashMap::<String, i64>::new();
+    values.insert("a".into(), 9);
+    values.insert("b".into(), 3);
+    assert!(conv.eval(&values).is_err());
+    register_function(FnId::from("conv"), |args: &[i64]| {
+        if args[1] == 0 {
+            Err("Stride can not be zero.".into())
+        } else {
+            Ok(args[0] / args[1])
+        }
+    });
+    assert_eq!(conv.eval(&values), Ok(3));
+    assert_eq!(reduce(&conv, &values), 3);
+    values.remove("b");
+    assert_eq!(
+        reduce(&conv, &values),
+        apply(FnId::from("conv"), &[TestPolynomial::from(10), b.clone()])
+    );
+    values.insert("b".into(), 0);
+    assert_eq!(conv.eval(&values), Err(("".into(), "Stride can not be zero.".into())));
+    // Constant arguments are only evaluated on request
+    let constant = apply(FnId::from("conv"), &[TestPolynomial::from(7), TestPolynomial::from(2)]);
+    assert!(constant != 3);
+    assert_eq!(constant.eval(&values), Ok(3));
+    assert_eq!(reduce(&constant, &values), 3);
+    assert!(unregister_function::<i64>(&FnId::from("conv")));
+    assert!(conv.eval(&values).is_err());
+    assert!(constant.eval(&values).is_err());
+}
+
 #[test]
 pub fn unique_identifiers_test() {
     let thirteen = TestPolynomial::from(13);
@@ -215,6 +952,109 @@ pub fn unique_identifiers_test() {
     assert_eq!(set.len(), 2);
 }
 
+#[test]
+pub fn substitute_test() {
+    let a: TestPolynomial = variable("a".into());
+    let b: TestPolynomial = variable("b".into());
+    let c: TestPolynomial = variable("c".into());
+    let two = TestPolynomial::from(2);
+    // a^2 + floor(ab, 2)
+    let poly = &a * &a + floor(&a * &b, &two);
+
+    let mut values = HashMap::<String, TestPolynomial>::new();
+    values.insert("a".into(), &c + 1);
+    // c^2 + 2c + 1 + floor(bc + b, 2)
+    assert_eq!(substitute(&poly, &values), &c * &c + 2 * &c + 1 + floor(&b * &c + &b, &two));
+    // Substituting a with 2b makes the floor exact
+    values.insert("a".into(), 2 * &b);
+    assert_eq!(substitute(&poly, &values), 5 * &b * &b);
+
+    // The division by a is kept if it is not exact after the substitution
+    let n: LaurentPolynomial = variable("n".into());
+    let a: LaurentPolynomial = variable("a".into());
+    let b: LaurentPolynomial = variable("b".into());
+    let mut values = HashMap::<String, LaurentPolynomial>::new();
+    values.insert("a".into(), &b + 1);
+    assert_eq!(substitute(&(&n / &a), &values), &n / &a);
+    values.insert("n".into(), 3 * &b + 3);
+    assert_eq!(substitute(&(&n / &a), &values), 3);
+    // A monomial value divides exactly
+    values.insert("a".into(), 2 * &b);
+    values.remove("n");
+    assert_eq!(substitute(&(2 * &n / &a), &values), &n / &b);
+}
+
+#[test]
+pub fn forward_difference_test() {
+    let a: TestPolynomial = variable("a".into());
+    let b: TestPolynomial = variable("b".into());
+    // a^3 + ab + 4
+    let poly = &a * &a * &a + &a * &b + 4;
+    // 3a^2 + 3a + b + 1
+    assert_eq!(forward_difference(&poly, &"a".into()), 3 * &a * &a + 3 * &a + &b + 1);
+    assert_eq!(forward_difference(&poly, &"b".into()), a.clone());
+    assert_eq!(forward_difference(&poly, &"c".into()), TestPolynomial::default());
+
+    // max(a, b) has no derivative, but its difference can be represented
+    let with_max = max(&a, &b);
+    let difference = forward_difference(&with_max, &"a".into());
+    assert_eq!(difference, max(&a + 1, &b) - max(&a, &b));
+    let mut values = HashMap::<String, i64>::new();
+    values.insert("a".into(), 3);
+    values.insert("b".into(), 7);
+    assert_eq!(difference.eval(&values), Ok(0));
+    values.insert("a".into(), 7);
+    assert_eq!(difference.eval(&values), Ok(1));
+}
+
+#[test]
+pub fn sum_over_test() {
+    let a: TestPolynomial = variable("a".into());
+    let i: TestPolynomial = variable("i".into());
+    let n: TestPolynomial = variable("n".into());
+    let m: TestPolynomial = variable("m".into());
+    let one = TestPolynomial::from(1);
+    let two = TestPolynomial::from(2);
+
+    // sum_{i=1}^{n} i = floor(n^2 + n, 2)
+    assert_eq!(sum_over(&i, &"i".into(), &one, &n), Ok(floor(&n * &n + &n, &two)));
+    // sum_{i=1}^{n} 2i = n^2 + n
+    assert_eq!(sum_over(2 * &i, &"i".into(), &one, &n), Ok(&n * &n + &n));
+    // sum_{i=1}^{n} a = an
+    assert_eq!(sum_over(&a, &"i".into(), &one, &n), Ok(&a * &n));
+
+    // sum_{i=1}^{n} i^2 = floor(2n^3 + 3n^2 + n, 6), which is kept as an opaque composite
+    let squares = sum_over(&i * &i, &"i".into(), &one, &n).unwrap();
+    let six = TestPolynomial::from(6);
+    assert_eq!(squares, floor(2 * &n * &n * &n + 3 * &n * &n + &n, &six));
+    assert_eq!(squares.monomials.len(), 1);
+    assert_eq!(squares.monomials[0].powers.len(), 1);
+    let triangle = sum_over(&i, &"i".into(), &one, &n).unwrap();
+    assert_eq!(2 * &triangle, 2 * floor(&n * &n + &n, &two));
+    assert!(2 * &triangle != &n * &n + &n);
+    assert!(squares.derivative(&"n".into()).is_err());
+
+    // sum_{i=m}^{n} a i^3 + 2i^2 - 5
+    let poly = &a * &i * &i * &i + 2 * &i * &i - 5;
+    let sum = sum_over(&poly, &"i".into(), &m, &n).unwrap();
+    let mut values = HashMap::<String, i64>::new();
+    values.insert("a".into(), 3);
+    for m_val in -4..4 {
+        for n_val in m_val..(m_val + 7) {
+            values.insert("m".into(), m_val);
+            values.insert("n".into(), n_val);
+            let expected: i64 = (m_val..(n_val + 1)).map(|x| 3 * x * x * x + 2 * x * x - 5).sum();
+            assert_eq!(sum.eval(&values), Ok(expected));
+        }
+    }
+
+    let with_floor = floor(&i, &two);
+    assert_eq!(
+        sum_over(&with_floor, &"i".into(), &one, &n),
+        Err(("i".into(), "The variable i is part of the composite floor(i, 2).".into()))
+    );
+}
+
 #[test]
 pub fn deduce_values_test1() {
     let a: TestPolynomial = variable("a".into());
@@ -460,3 +1300,42 @@ pub fn deduce_values_test_fails() {
     implicit_values.push((poly2.clone(), val2));
     assert_eq!(deduce_values(&implicit_values), Err("Could not deduce all variables.".into()));
 }
+
+#[test]
+pub fn eval_f64_test() {
+    let a: TestPolynomial = variable("a".into());
+    let b: TestPolynomial = variable("b".into());
+    let mut values = HashMap::new();
+    values.insert("a".to_string(), 1e12);
+    values.insert("b".to_string(), 7.0);
+
+    // a^2 b overflows i64, but not f64
+    assert_eq!((&a * &a * &b).eval_f64(&values), 7e24);
+    assert_eq!(floor(&a, &b).eval_f64(&values), (1e12f64 / 7.0).floor());
+    assert_eq!(ceil(&a, &b).eval_f64(&values), (1e12f64 / 7.0).ceil());
+    assert_eq!(modulo(&a, &b).eval_f64(&values), 1e12 % 7.0);
+    assert_eq!(max(&a, &b).eval_f64(&values), 1e12);
+    assert_eq!(min(&a, &b).eval_f64(&values), 7.0);
+    assert_eq!(sign(&(&b - 7)).eval_f64(&values), 0.0);
+    assert_eq!(gcd(&(3 * &b), &(&b * &b)).eval_f64(&values), 7.0);
+    assert_eq!(exp2(&b).eval_f64(&values), 128.0);
+
+    // The values of a `Monomial` can be fractions
+    let mut fractions = HashMap::new();
+    fractions.insert("a".to_string(), 0.5);
+    fractions.insert("b".to_string(), 3.0);
+    assert_eq!((3 * &a * &b).monomials[0].eval_f64(&fractions), 4.5);
+    assert_eq!(floor(&(3 * &a), &b).eval_f64(&fractions), 0.0);
+    assert!(a.eval_f64(&HashMap::new()).is_nan());
+
+    // A missing variable is not dropped by min or max
+    let mut only_b = HashMap::new();
+    only_b.insert("b".to_string(), 3.0);
+    assert!(max(&a, &b).eval_f64(&only_b).is_nan());
+    assert!(min(&a, &b).eval_f64(&only_b).is_nan());
+    assert!(gcd(&a, &b).eval_f64(&only_b).is_nan());
+
+    register_function(FnId::from("half"), |args: &[f64]| Ok(args[0] / 2.0));
+    assert_eq!(apply(FnId::from("half"), &[&b]).eval_f64(&values), 3.5);
+    assert!(unregister_function::<f64>(&FnId::from("half")));
+}
@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+extern crate num;
+extern crate symbolic_polynomials;
+use num::FromPrimitive;
+use symbolic_polynomials::*;
+
+#[allow(dead_code)]
+type TestPolynomial = Polynomial<String, i64, u8>;
+#[allow(dead_code)]
+type Mod7 = ModInt<7>;
+#[allow(dead_code)]
+type ModPolynomial = Polynomial<String, Mod7, u8>;
+
+fn to_mod7(c: &i64) -> Mod7 {
+    Mod7::from_i64(*c).unwrap()
+}
+
+#[test]
+pub fn field_test() {
+    let three = Mod7::new(3);
+    let five = Mod7::new(5);
+
+    assert_eq!(Mod7::new(12), five);
+    assert_eq!(Mod7::from_i64(-2), Some(five));
+    assert_eq!(three + five, Mod7::new(1));
+    assert_eq!(three - five, Mod7::new(5));
+    assert_eq!(three * five, Mod7::new(1));
+    assert_eq!(-three, Mod7::new(4));
+    assert_eq!(three.inverse(), Some(five));
+    assert_eq!(Mod7::new(0).inverse(), None);
+    // Every division is exact
+    assert_eq!(Mod7::new(1) / three, five);
+    assert_eq!(Mod7::new(1).exact_div(&three), Some(five));
+    assert_eq!(Mod7::new(1).floor_div(&three), five);
+    assert_eq!(Mod7::new(1).floor_mod(&three), Mod7::new(0));
+    assert_eq!(Mod7::new(2).pow(6), Mod7::new(1));
+    assert_eq!(format!("{}", Mod7::new(9)), "2");
+    assert_eq!(Mod7::default(), Mod7::new(0));
+
+    // The largest prime below 2^64
+    type ModLarge = ModInt<18446744073709551557>;
+    let x = ModLarge::from_i64(-1).unwrap();
+    assert_eq!(x * x, ModLarge::new(1));
+    assert_eq!(x.inverse(), Some(x));
+    assert_eq!(ModInt::<2>::new(3) + ModInt::<2>::new(1), ModInt::<2>::new(0));
+}
+
+#[test]
+pub fn map_coefficients_test() {
+    let a: TestPolynomial = variable("a".into());
+    let b: TestPolynomial = variable("b".into());
+    let a_mod: ModPolynomial = variable("a".into());
+    let b_mod: ModPolynomial = variable("b".into());
+
+    // (a + b)^7 = a^7 + b^7 in characteristic 7
+    let p = (0..7).fold(TestPolynomial::from(1), |acc, _| acc * (&a + &b));
+    assert_eq!(p.monomials.len(), 8);
+    let p_mod = p.map_coefficients(&to_mod7).unwrap();
+    let expected = (0..7).fold(ModPolynomial::from(Mod7::new(1)), |acc, _| acc * &a_mod) +
+                   (0..7).fold(ModPolynomial::from(Mod7::new(1)), |acc, _| acc * &b_mod);
+    assert_eq!(p_mod, expected);
+
+    let mut values = HashMap::new();
+    values.insert("a".into(), 10);
+    values.insert("b".into(), 20);
+    let mut values_mod = HashMap::new();
+    values_mod.insert("a".into(), Mod7::new(10));
+    values_mod.insert("b".into(), Mod7::new(20));
+    assert_eq!(p_mod.eval(&values_mod), Ok(to_mod7(&p.eval(&values).unwrap())));
+
+    // Reducing modulo 7 does not commute with composites, hence they are rejected
+    let q = max(&(3 * &a + 1), &(&b - 6)) + 14 * &a;
+    assert!(q.map_coefficients(&to_mod7).is_err());
+    assert!(floor(&a, &b).map_coefficients(&to_mod7).is_err());
+
+    // The parts of a polynomial are converted in the same way
+    let m_mod = p.monomials[1].map_coefficients(&to_mod7).unwrap();
+    assert_eq!(m_mod.coefficient, Mod7::new(0));
+    assert_eq!(m_mod.powers.len(), p.monomials[1].powers.len());
+    let condition = Condition::new(8 * &a, Relation::Less, &b - 1);
+    let condition_mod = condition.map_coefficients(&to_mod7).unwrap();
+    assert_eq!(condition_mod.left, a_mod);
+    assert_eq!(condition_mod.right, &b_mod + Mod7::new(6));
+    assert!(floor(&a, &b).monomials[0].powers[0].0.map_coefficients(&to_mod7).is_err());
+}
@@ -1,11 +1,20 @@
 use std::collections::HashMap;
+extern crate num;
 extern crate symbolic_polynomials;
+use num::bigint::BigInt;
+use num::rational::Ratio;
 use symbolic_polynomials::*;
 
 #[allow(dead_code)]
 type TestMonomial = Monomial<String, i64, u8>;
 #[allow(dead_code)]
 type TestPolynomial = Polynomial<String, i64, u8>;
+#[allow(dead_code)]
+type LaurentPolynomial = Polynomial<String, i64, i8>;
+#[allow(dead_code)]
+type RationalPolynomial = Polynomial<String, Ratio<i64>, u8>;
+#[allow(dead_code)]
+type BigPolynomial = Polynomial<String, BigInt, u8>;
 
 #[test]
 pub fn constructor() {
@@ -218,6 +227,42 @@ pub fn div_test() {
     assert_eq!(product.checked_div(&1.into()).unwrap(), product);
 }
 
+#[test]
+pub fn laurent_test() {
+    let n: LaurentPolynomial = variable("n".into());
+    let a: LaurentPolynomial = variable("a".into());
+    let b: LaurentPolynomial = variable("b".into());
+    // n a^-1
+    let stride = &n / &a;
+    // (n + 1) a^-1 b
+    let sum = (&n + 1) * &b / &a;
+    let values = &mut HashMap::<String, i64>::new();
+    values.insert("n".into(), 12);
+    values.insert("a".into(), 4);
+    values.insert("b".into(), 2);
+
+    assert_eq!(format!("{}", stride), "a^-1n");
+    assert_eq!(stride.to_code(&|x: String| x), "n / a");
+    assert_eq!(format!("{}", &a / &(&a * &b)), "b^-1");
+    assert_eq!((&a / &(&a * &b)).to_code(&|x: String| x), "1 / b");
+    assert_eq!(&stride * &a, n);
+    assert_eq!(&stride * &a * &b / &b, n);
+    assert_eq!(stride.eval(values), Ok(3));
+    values.insert("n".into(), 13);
+    assert!(stride.eval(values).is_err());
+    // 26 / 4 + 2 / 4
+    assert_eq!(sum.eval(values), Ok(7));
+    assert_eq!(sum.to_code(&|x: String| x), "(b * n + b) / (a)");
+    assert_eq!((&sum + &stride / &a).to_code(&|x: String| x), "(a * b * n + a * b + n) / (a * a)");
+    values.insert("a".into(), 0);
+    assert!(stride.eval(values).is_err());
+    assert!(stride.coefficients(&"a".into()).is_err());
+
+    let n: TestPolynomial = variable("n".into());
+    let a: TestPolynomial = variable("a".into());
+    assert!(n.laurent_div(&a.monomials[0]).is_none());
+}
+
 #[test]
 pub fn add_test() {
     let a_mon = TestMonomial {
@@ -276,6 +321,9 @@ pub fn sub_test() {
     assert_eq!(&a_plus_b_plus_1 - &a, &b + 1);
     assert_eq!(&a_plus_b_plus_1 - &b, &a + 1);
     assert_eq!(&a_plus_b_plus_1 - &(&a + &b), 1);
+    // Terms of the right side which are inserted before the left ones are all kept
+    assert_eq!(&b - &(&a + 1), -&a + &b - 1);
+    assert_eq!(&b - &(&a * &a + &a + 1), -&a * &a - &a + &b - 1);
 }
 
 #[test]
@@ -348,3 +396,265 @@ pub fn eval_test() {
         Err(("".into(), "Attempting division by zero.".into()))
     );
 }
+
+#[test]
+pub fn eval_interval_test() {
+    let a: TestPolynomial = variable("a".into());
+    let b: TestPolynomial = variable("b".into());
+    let c: TestPolynomial = variable("c".into());
+
+    let mut intervals = HashMap::<String, (i64, i64)>::new();
+    intervals.insert("a".into(), (1, 256));
+    intervals.insert("b".into(), (1, 4096));
+    intervals.insert("c".into(), (-3, 2));
+
+    assert_eq!(TestPolynomial::default().eval_interval(&intervals), Ok((0, 0)));
+    assert_eq!((&a * &b * 4).eval_interval(&intervals), Ok((4, 4 * 256 * 4096)));
+    assert_eq!((&a - &b).eval_interval(&intervals), Ok((1 - 4096, 255)));
+    assert_eq!((&c * &c).eval_interval(&intervals), Ok((0, 9)));
+    assert_eq!((&c * &c * &c).eval_interval(&intervals), Ok((-27, 8)));
+    assert_eq!((&a * &c).eval_interval(&intervals), Ok((-768, 512)));
+    assert_eq!(floor(&b, TestPolynomial::from(8)).eval_interval(&intervals), Ok((0, 512)));
+    assert_eq!(ceil(&b, &a).eval_interval(&intervals), Ok((1, 4096)));
+    assert_eq!(max(&a, &b).eval_interval(&intervals), Ok((1, 4096)));
+    assert_eq!(min(&a, &c).eval_interval(&intervals), Ok((-3, 2)));
+    assert_eq!(abs(&c).eval_interval(&intervals), Ok((0, 3)));
+    assert_eq!(exp2(&c).eval_interval(&intervals), Ok((1, 4)));
+    assert_eq!(
+        (&a + &c).eval_interval(&HashMap::new()),
+        Err(("a".into(), "Value not provided for a.".into()))
+    );
+    assert_eq!(floor(&a, &c - 5).eval_interval(&intervals), Ok((-86, -1)));
+    assert_eq!(
+        floor(&a, &c - &c).eval_interval(&intervals),
+        Err(("".into(), "Attempting division by zero.".into()))
+    );
+
+    // The bounds must contain every value attained over the intervals
+    let mut small = HashMap::<String, (i64, i64)>::new();
+    small.insert("a".into(), (-4, 5));
+    small.insert("b".into(), (1, 6));
+    small.insert("c".into(), (-3, 2));
+    let expressions = vec![
+        &a * &a * &b - 3 * &a * &c + &c * &c * &c,
+        floor(&a * &c + 7, &b),
+        ceil(&a - &c, &c),
+        modulo(&a * &a + &c, &c),
+        trunc_div(&a, &c - &b),
+        round_div(&a * &b, &c + 4),
+        max(&a * &c, &b - &a) - min(&a, &c),
+        sign(&a - &c) * abs(&a * &c - 1),
+        gcd(&a, &b) + lcm(&b, &c),
+        exp2(&b) + log2_floor(&b * 3) - log2_ceil(&b + 2),
+        select(Condition::new(&a, Relation::Less, &c), &a * &b, &c - &b),
+    ];
+    let mut values = HashMap::<String, i64>::new();
+    for expression in &expressions {
+        let (low, high) = expression.eval_interval(&small).unwrap();
+        for a_val in -4..6 {
+            for b_val in 1..7 {
+                for c_val in -3..3 {
+                    values.insert("a".into(), a_val);
+                    values.insert("b".into(), b_val);
+                    values.insert("c".into(), c_val);
+                    if let Ok(value) = expression.eval(&values) {
+                        assert!(low <= value && value <= high, "{} = {}", expression, value);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+pub fn derivative_test() {
+    let a: TestPolynomial = variable("a".into());
+    let b: TestPolynomial = variable("b".into());
+    // 3a^3b + 2ab^2 + 5b + 7
+    let poly = 3 * &a * &a * &a * &b + 2 * &a * &b * &b + 5 * &b + 7;
+
+    // 9a^2b + 2b^2
+    assert_eq!(poly.derivative(&"a".into()), Ok(9 * &a * &a * &b + 2 * &b * &b));
+    // 3a^3 + 4ab + 5
+    assert_eq!(poly.derivative(&"b".into()), Ok(3 * &a * &a * &a + 4 * &a * &b + 5));
+    assert_eq!(poly.derivative(&"c".into()), Ok(TestPolynomial::default()));
+    assert_eq!(TestPolynomial::from(3).derivative(&"a".into()), Ok(TestPolynomial::default()));
+
+    // a * floor(b, 2) is differentiable with respect to a, but not b
+    let with_floor = &a * floor(&b, TestPolynomial::from(2));
+    assert_eq!(with_floor.derivative(&"a".into()), Ok(floor(&b, TestPolynomial::from(2))));
+    assert_eq!(
+        with_floor.derivative(&"b".into()),
+        Err(("b".into(), "Can not differentiate floor(b, 2) with respect to b.".into()))
+    );
+}
+
+#[test]
+pub fn resultant_test() {
+    let a: TestPolynomial = variable("a".into());
+    let b: TestPolynomial = variable("b".into());
+    let x: TestPolynomial = variable("x".into());
+    let y: TestPolynomial = variable("y".into());
+    let var = "x".into();
+
+    // Res(x^2 - a, x - b) = b^2 - a
+    assert_eq!((&x * &x - &a).resultant(&(&x - &b), &var), Ok(&b * &b - &a));
+    // Res(xy - a, x + y - b) = y^2 - by + a
+    assert_eq!(
+        (&x * &y - &a).resultant(&(&x + &y - &b), &var),
+        Ok(&y * &y - &b * &y + &a)
+    );
+    // (x - 1)(x - 2) and (x - 2)(x + 5) have a common root
+    let p = (&x - 1) * (&x - 2);
+    let q = (&x - 2) * (&x + 5);
+    assert_eq!(p.resultant(&q, &var), Ok(TestPolynomial::default()));
+    // Res(3, x^2 + 1) = 9
+    assert_eq!(TestPolynomial::from(3).resultant(&(&x * &x + 1), &var), Ok(9.into()));
+    assert_eq!(
+        floor(&x, &a).resultant(&x, &var),
+        Err(("x".into(), "The variable x is part of the composite floor(x, a).".into()))
+    );
+}
+
+#[test]
+pub fn discriminant_test() {
+    let a: TestPolynomial = variable("a".into());
+    let b: TestPolynomial = variable("b".into());
+    let c: TestPolynomial = variable("c".into());
+    let x: TestPolynomial = variable("x".into());
+    let var = "x".into();
+
+    // ax^2 + bx + c => b^2 - 4ac
+    assert_eq!(
+        (&a * &x * &x + &b * &x + &c).discriminant(&var),
+        Ok(&b * &b - 4 * &a * &c)
+    );
+    // x^3 + ax + b => -4a^3 - 27b^2
+    assert_eq!(
+        (&x * &x * &x + &a * &x + &b).discriminant(&var),
+        Ok(-4 * &a * &a * &a - 27 * &b * &b)
+    );
+    // (x - a)^2 has a repeated root
+    assert_eq!(((&x - &a) * (&x - &a)).discriminant(&var), Ok(TestPolynomial::default()));
+    assert_eq!((3 * &x + &a).discriminant(&var), Ok(1.into()));
+    assert_eq!(
+        a.discriminant(&var),
+        Err(("x".into(), "The polynomial a does not depend on x.".into()))
+    );
+}
+
+#[test]
+pub fn greatest_common_divisor_test() {
+    let a: TestPolynomial = variable("a".into());
+    let b: TestPolynomial = variable("b".into());
+    let c: TestPolynomial = variable("c".into());
+    let floor_a = floor(&a, TestPolynomial::from(2));
+
+    // a^2 - b^2, a^2 + 2ab + b^2 => a + b
+    assert_eq!(
+        (&a * &a - &b * &b).greatest_common_divisor(&(&a * &a + 2 * &a * &b + &b * &b)),
+        &a + &b
+    );
+    // 6a^2b, 4ab^2 => 2ab
+    assert_eq!((6 * &a * &a * &b).greatest_common_divisor(&(4 * &a * &b * &b)), 2 * &a * &b);
+    // (a + c)(b + 2), -(a + c)(b - 2)c => a + c
+    assert_eq!(
+        ((&a + &c) * (&b + 2)).greatest_common_divisor(&(-(&a + &c) * (&b - 2) * &c)),
+        &a + &c
+    );
+    assert_eq!((&a + 1).greatest_common_divisor(&(&a + 2)), 1);
+    assert_eq!((-2 * &a - 2).greatest_common_divisor(&TestPolynomial::default()), 2 * &a + 2);
+    assert_eq!(
+        (&floor_a * (&b + 1)).greatest_common_divisor(&(&floor_a * &floor_a - &floor_a)),
+        floor_a
+    );
+}
+
+#[test]
+pub fn rational_coefficients_test() {
+    let half = Ratio::new(1, 2);
+    let a: RationalPolynomial = variable("a".into());
+    let b: RationalPolynomial = variable("b".into());
+    let values: HashMap<String, Ratio<i64>> =
+        [("a".into(), Ratio::from_integer(3)), ("b".into(), Ratio::from_integer(2))]
+            .iter()
+            .cloned()
+            .collect();
+
+    // (a + 2b) / 2 keeps the half coefficients
+    let p = (&a + &b * Ratio::from_integer(2)) / Ratio::from_integer(2);
+    assert_eq!(p.monomials[0].coefficient, half);
+    assert_eq!(p.eval(&values), Ok(Ratio::new(7, 2)));
+    assert_eq!(&p * Ratio::from_integer(2), &a + &b * Ratio::from_integer(2));
+    // Fractional coefficients are parenthesized
+    assert_eq!(format!("{}", p), "(1/2)a + b");
+    assert_eq!(p.to_code(&|x: String| x), "(1/2) * a + b");
+    assert_eq!(format!("{}", -&p - half), "- (1/2)a - b - (1/2)");
+    assert_eq!((&b * Ratio::from_integer(2)).to_code(&|x: String| x), "2 * b");
+
+    // floor and ceil of the rational value
+    let one = RationalPolynomial::from(Ratio::from_integer(1));
+    let seven_halves = RationalPolynomial::from(Ratio::new(7, 2));
+    assert_eq!(floor(&seven_halves, RationalPolynomial::from(half)), Ratio::from_integer(7));
+    assert_eq!(floor(&seven_halves, &one), Ratio::from_integer(3));
+    assert_eq!(ceil(&seven_halves, &one), Ratio::from_integer(4));
+    assert_eq!(floor(&p, &one).eval(&values), Ok(Ratio::from_integer(3)));
+    assert_eq!(ceil(&p, &one).eval(&values), Ok(Ratio::from_integer(4)));
+    let two = RationalPolynomial::from(Ratio::from_integer(2));
+    assert_eq!(trunc_div(&a, &two).eval(&values), Ok(Ratio::from_integer(1)));
+    assert_eq!(modulo(&a, &two).eval(&values), Ok(Ratio::from_integer(1)));
+}
+
+#[test]
+pub fn big_coefficients_test() {
+    let a: BigPolynomial = variable("a".into());
+    let two = BigInt::from(2);
+    let values: HashMap<String, BigInt> = [("a".into(), two.clone())].iter().cloned().collect();
+
+    // (a + 1)^100 overflows any primitive type
+    let one = BigPolynomial::from(BigInt::from(1));
+    let p = (0..100).fold(one.clone(), |acc, _| acc * (&a + &one));
+    assert_eq!(p.eval(&values), Ok(::num::pow(BigInt::from(3), 100)));
+    assert_eq!(p.monomials.len(), 101);
+
+    let three_p = BigInt::from(3) * &p;
+    assert_eq!(three_p.eval(&values), Ok(::num::pow(BigInt::from(3), 101)));
+    assert_eq!((BigInt::from(5) - &a).eval(&values), Ok(BigInt::from(3)));
+    assert!(BigInt::from(5) == BigPolynomial::from(BigInt::from(5)));
+    assert_eq!(
+        floor(&p, BigPolynomial::from(two.clone())).eval(&values),
+        Ok(::num::pow(BigInt::from(3), 100) / two)
+    );
+
+    let b: Polynomial<String, i128, u8> = variable("b".into());
+    assert_eq!(5 - 3 * &b, -(3 * &b - 5));
+    assert!(7i128 == Polynomial::<String, i128, u8>::from(7));
+}
+
+#[test]
+pub fn conversion_test() {
+    let a: TestPolynomial = variable("a".into());
+    let b: TestPolynomial = variable("b".into());
+    let x: Polynomial<u32, i32, u16> = variable(1);
+    let y: Polynomial<u32, i32, u16> = variable(2);
+    let p = floor(&(3 * &a * &b + 2), &(&b * &b)) + max(&a, &(&b + 1)) + 5 * &a * &a * &b - 7;
+    let expected = floor(&(3 * &x * &y + 2), &(&y * &y)) + max(&x, &(&y + 1)) +
+                   5 * &x * &x * &y - 7;
+
+    // The ordering of the identifiers is reversed, "a" > "b" but 1 < 2
+    let converted = p.map_ids(&|id: &String| if id == "a" { 1 } else { 2 })
+        .try_map_coefficients(&|c: &i64| ::num::NumCast::from(*c))
+        .unwrap()
+        .try_map_powers(&|p: &u8| ::num::NumCast::from(*p))
+        .unwrap();
+    assert_eq!(converted, expected);
+    assert_eq!(converted.to_string(), expected.to_string());
+
+    let big = TestPolynomial::from(1 << 40) * &a;
+    assert!(big.try_map_coefficients(&|c: &i64| -> Option<i32> { ::num::NumCast::from(*c) })
+        .is_err());
+    let high = (0..300).fold(Polynomial::<u32, i32, u16>::from(1), |acc, _| acc * &x);
+    assert!(high.try_map_powers(&|p: &u16| -> Option<u8> { ::num::NumCast::from(*p) }).is_err());
+    let square = (&a * &a).try_map_powers(&|p: &u8| -> Option<u16> { ::num::NumCast::from(*p) });
+    assert_eq!(square.unwrap().monomials[0].powers[0].1, 2u16);
+}
@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+extern crate symbolic_polynomials;
+use symbolic_polynomials::*;
+
+#[allow(dead_code)]
+type TestPolynomial = Polynomial<String, i64, u8>;
+#[allow(dead_code)]
+type TestRational = RationalFunction<String, i64, u8>;
+#[allow(dead_code)]
+type LaurentPolynomial = Polynomial<String, i64, i8>;
+#[allow(dead_code)]
+type LaurentRational = RationalFunction<String, i64, i8>;
+
+#[test]
+pub fn constructor_test() {
+    let a: TestPolynomial = variable("a".into());
+    let b: TestPolynomial = variable("b".into());
+    // (a^2 - b^2) / (2a + 2b) => (a - b) / 2
+    let reduced = TestRational::new(&a * &a - &b * &b, 2 * &a + 2 * &b);
+
+    assert_eq!(reduced.numerator, &a - &b);
+    assert_eq!(reduced.denominator, 2);
+    assert_eq!(TestRational::new(a.clone(), -&b), TestRational::new(-&a, b.clone()));
+    assert_eq!(TestRational::new(TestPolynomial::default(), b.clone()), TestRational::from(0));
+    assert_eq!(format!("{}", TestRational::new(a.clone(), &b + 1)), "(a) / (b + 1)");
+    assert_eq!(TestRational::new(a.clone(), &b + 1).to_code(&|x: String| x), "(a) / (b + 1)");
+    assert_eq!(TestRational::from(&a).to_code(&|x: String| x), "a");
+}
+
+#[test]
+pub fn laurent_test() {
+    let a: LaurentPolynomial = variable("a".into());
+    let b: LaurentPolynomial = variable("b".into());
+    // (1 + b / a) / b => (a + b) / (a * b)
+    let reduced = LaurentRational::new(1 + &b / &a, b.clone());
+    assert_eq!(reduced.numerator, &a + &b);
+    assert_eq!(reduced.denominator, &a * &b);
+    // (b / a) / (1 / a^2) => a * b
+    let reduced = LaurentRational::new(&b / &a, LaurentPolynomial::from(1) / &a / &a);
+    assert_eq!(reduced, LaurentRational::from(&a * &b));
+    assert_eq!(LaurentRational::from(&b / &a), LaurentRational::new(b.clone(), a.clone()));
+    assert_eq!(LaurentRational::from(&b / &a).denominator, a);
+}
+
+#[test]
+pub fn arithmetic_test() {
+    let a: TestPolynomial = variable("a".into());
+    let b: TestPolynomial = variable("b".into());
+    let over_a = 1 / TestRational::from(&a);
+    let over_b = 1 / TestRational::from(&b);
+
+    // 1 / a + 1 / b => (a + b) / ab
+    assert_eq!(&over_a + &over_b, TestRational::new(&a + &b, &a * &b));
+    // a / (a + b) + b / (a + b) => 1
+    let total = TestRational::new(a.clone(), &a + &b) + TestRational::new(b.clone(), &a + &b);
+    assert_eq!(total, TestRational::from(1));
+    assert_eq!(total.to_polynomial(), Some(1.into()));
+    // (a / b) * (b^2 / a) => b
+    assert_eq!(
+        TestRational::new(a.clone(), b.clone()) * TestRational::new(&b * &b, a.clone()),
+        TestRational::from(&b)
+    );
+    assert_eq!(&over_a * &a, TestRational::from(1));
+    assert_eq!(&over_a - &over_a, TestRational::from(0));
+    assert_eq!((&over_a / &over_b).numerator, b);
+    assert_eq!(-(2 * &over_a) + 3, TestRational::new(3 * &a - 2, a.clone()));
+    assert!(over_a.to_polynomial().is_none());
+
+    let mut value = TestRational::from(&a);
+    value /= &b;
+    value *= 2;
+    value += &a;
+    value -= TestRational::new(a.clone(), b.clone());
+    assert_eq!(value, TestRational::new(&a * &b + &a, b.clone()));
+}
+
+#[test]
+pub fn eval_test() {
+    let a: TestPolynomial = variable("a".into());
+    let b: TestPolynomial = variable("b".into());
+    // Throughput of a elements in b steps
+    let throughput = TestRational::new(&a * &a + &a, &b + 1);
+    let values = &mut HashMap::<String, i64>::new();
+    values.insert("a".into(), 5);
+    values.insert("b".into(), 2);
+
+    assert_eq!(throughput.eval(values), Ok(10));
+    values.insert("b".into(), 3);
+    assert!(throughput.eval(values).is_err());
+    values.insert("b".into(), -1);
+    assert_eq!(
+        throughput.eval(values),
+        Err(("".into(), "Attempting division by zero.".into()))
+    );
+}